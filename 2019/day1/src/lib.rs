@@ -0,0 +1,53 @@
+use aoc::{
+    parse::{self, integer, lines},
+    Error, Result, Solution,
+};
+
+pub mod rocket;
+
+pub fn parse(input: &str) -> Result<Vec<u64>> {
+    Ok(parse::complete(lines(integer()), input)?)
+}
+
+fn part_one(input: &str) -> Result<u64> {
+    rocket::sum(&parse(input)?, |mass| {
+        rocket::fuel(mass)
+            .ok_or_else(|| Error::invalid(format!("module of mass {} needs negative fuel", mass)))
+    })
+}
+
+fn part_two(input: &str) -> Result<u64> {
+    rocket::sum(&parse(input)?, |mass| Ok(rocket::total_fuel(mass)))
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2019, 1).part(part_one).part(part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_examples() {
+        assert_eq!(part_one("12"), Ok(2));
+        assert_eq!(part_one("14"), Ok(2));
+        assert_eq!(part_one("1969"), Ok(654));
+        assert_eq!(part_one("100756"), Ok(33583));
+    }
+
+    #[test]
+    fn part_two_examples() {
+        assert_eq!(part_two("14"), Ok(2));
+        assert_eq!(part_two("1969"), Ok(966));
+        assert_eq!(part_two("100756"), Ok(50346));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_one("12\n1x").is_err());
+        assert!(part_one("12\n5").is_err());
+        assert_eq!(part_two("5"), Ok(0));
+        assert!(part_two("-14").is_err());
+    }
+}
@@ -1,55 +1,32 @@
-use std::{error::Error, io::Read};
-
-fn fuel(x: u64) -> u64 {
-    let w = (x / 3).saturating_sub(2);
-    match w {
-        0 => w,
-        _ => w + fuel(w),
-    }
-}
-
-fn part_one(input: &str) -> u64 {
-    input
-        .lines()
-        .filter_map(|x| x.parse::<u64>().ok())
-        .map(|x| x / 3 - 2)
-        .sum()
-}
-
-fn part_two(input: &str) -> u64 {
-    input
-        .lines()
-        .filter_map(|x| x.parse::<u64>().ok())
-        .map(fuel)
-        .sum()
-}
-
-fn main() -> Result<(), Box<dyn Error>> {
-    let mut input = String::new();
-    std::io::stdin().read_to_string(&mut input)?;
-
-    println!("{}", part_one(&input));
-    println!("{}", part_two(&input));
-
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part_one_examples() {
-        assert_eq!(part_one("12"), 2);
-        assert_eq!(part_one("14"), 2);
-        assert_eq!(part_one("1969"), 654);
-        assert_eq!(part_one("100756"), 33583);
-    }
-
-    #[test]
-    fn part_two_examples() {
-        assert_eq!(part_two("14"), 2);
-        assert_eq!(part_two("1969"), 966);
-        assert_eq!(part_two("100756"), 50346);
+use aoc_2019_day1::rocket;
+use std::{env, fs, process::ExitCode};
+
+const USAGE: &str =
+    "usage: aoc-2019-day1 [--explain <mass> | --breakdown <input> | <solution options>]";
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let report = match args.as_slice() {
+        [flag, mass] if flag == "--explain" => mass
+            .parse()
+            .map(rocket::explain)
+            .map_err(|_| format!("invalid mass `{}`", mass)),
+        [flag, path] if flag == "--breakdown" => fs::read_to_string(path)
+            .map_err(|err| format!("{}: {}", path, err))
+            .and_then(|input| {
+                aoc_2019_day1::parse(&input).map_err(|err| format!("{}:{}", path, err))
+            })
+            .map(|masses| rocket::breakdown(&masses)),
+        _ => return aoc_2019_day1::solution().main(),
+    };
+    match report {
+        Ok(report) => {
+            print!("{}", report);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}\n{}", err, USAGE);
+            ExitCode::FAILURE
+        }
     }
 }
@@ -0,0 +1,130 @@
+//! The rocket equation: a module of some mass needs a third of it, rounded
+//! down, minus two in fuel, and that fuel needs fuel of its own.
+
+use aoc::{Error, Result};
+use std::fmt::Write;
+
+/// The fuel for `mass` alone, or `None` for masses below 6, which would need
+/// negative fuel.
+pub fn fuel(mass: u64) -> Option<u64> {
+    (mass / 3).checked_sub(2)
+}
+
+/// The fuel for `mass`, followed by the fuel for that fuel, until no more is
+/// needed.
+pub fn chain(mass: u64) -> impl Iterator<Item = u64> {
+    std::iter::successors(fuel(mass), |&mass| fuel(mass)).take_while(|&fuel| fuel != 0)
+}
+
+/// The fuel for `mass` including the fuel for the fuel.
+pub fn total_fuel(mass: u64) -> u64 {
+    chain(mass).sum()
+}
+
+/// Sums `amount` over the `masses`, failing on overflow.
+pub fn sum(masses: &[u64], amount: impl Fn(u64) -> Result<u64>) -> Result<u64> {
+    masses.iter().try_fold(0u64, |total, &mass| {
+        total
+            .checked_add(amount(mass)?)
+            .ok_or_else(|| Error::invalid("total fuel overflows"))
+    })
+}
+
+/// A table with the fuel and total fuel of each module, followed by the sums.
+pub fn breakdown(masses: &[u64]) -> String {
+    let mut output = format!(
+        "{:>6} {:>12} {:>12} {:>12}\n",
+        "module", "mass", "fuel", "total"
+    );
+    for (idx, &mass) in masses.iter().enumerate() {
+        let fuel = fuel(mass).map_or_else(|| "-".to_string(), |fuel| fuel.to_string());
+        let _ = writeln!(
+            output,
+            "{:>6} {:>12} {:>12} {:>12}",
+            idx + 1,
+            mass,
+            fuel,
+            total_fuel(mass)
+        );
+    }
+    let total = |amount: fn(u64) -> u64| {
+        masses
+            .iter()
+            .try_fold(0u64, |total, &mass| total.checked_add(amount(mass)))
+            .map_or_else(|| "overflow".to_string(), |total| total.to_string())
+    };
+    let _ = writeln!(
+        output,
+        "{:>6} {:>12} {:>12} {:>12}",
+        "sum",
+        total(|mass| mass),
+        total(|mass| fuel(mass).unwrap_or(0)),
+        total(total_fuel)
+    );
+    output
+}
+
+/// Each step of the fuel chain for one module.
+pub fn explain(mass: u64) -> String {
+    let chain = chain(mass).collect::<Vec<_>>();
+    let mut output = String::new();
+    for (mass, fuel) in std::iter::once(&mass).chain(&chain).zip(&chain) {
+        let _ = writeln!(output, "{} / 3 - 2 = {}", mass, fuel);
+    }
+    let _ = writeln!(
+        output,
+        "{} / 3 - 2 <= 0, so no more fuel is needed",
+        chain.last().unwrap_or(&mass)
+    );
+    let steps = chain.iter().map(u64::to_string).collect::<Vec<_>>();
+    let _ = match steps.len() {
+        0 | 1 => writeln!(output, "total: {}", total_fuel(mass)),
+        _ => writeln!(
+            output,
+            "total: {} = {}",
+            steps.join(" + "),
+            total_fuel(mass)
+        ),
+    };
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn reports() {
+        assert_eq!(fuel(5), None);
+        assert_eq!(fuel(6), Some(0));
+        assert_eq!(chain(1969).collect::<Vec<_>>(), [654, 216, 70, 21, 5]);
+        assert_eq!(
+            explain(14),
+            "14 / 3 - 2 = 2\n2 / 3 - 2 <= 0, so no more fuel is needed\ntotal: 2\n"
+        );
+        assert!(explain(100)
+            .ends_with("8 / 3 - 2 <= 0, so no more fuel is needed\ntotal: 31 + 8 = 39\n"));
+        assert_eq!(
+            breakdown(&[14, 3]).lines().last(),
+            Some("   sum           17            2            2")
+        );
+        assert!(sum(&[u64::MAX, u64::MAX], Ok).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn total_bounds_single_step(mass in any::<u64>()) {
+            let fuel = fuel(mass).unwrap_or(0);
+            let total = total_fuel(mass);
+            prop_assert!(total >= fuel);
+            prop_assert!(total <= mass / 2);
+        }
+
+        #[test]
+        fn chain_decreases(mass in any::<u64>()) {
+            let chain = chain(mass).collect::<Vec<_>>();
+            prop_assert!(chain.windows(2).all(|pair| pair[1] < pair[0]));
+        }
+    }
+}
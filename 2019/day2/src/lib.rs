@@ -0,0 +1,98 @@
+use aoc::{
+    parse::{self, integer, separated, tag},
+    Error, Result, Solution,
+};
+
+fn read(program: &[usize], address: usize) -> Result<usize> {
+    program
+        .get(address)
+        .copied()
+        .ok_or_else(|| Error::invalid(format!("address {} out of bounds", address)))
+}
+
+fn execute(mut program: Vec<usize>, noun: usize, verb: usize) -> Result<Vec<usize>> {
+    if program.len() < 3 {
+        return Err(Error::invalid("program too short"));
+    }
+    program[1] = noun;
+    program[2] = verb;
+
+    let mut pc = 0;
+    loop {
+        let op = read(&program, pc)?;
+        let value = match op {
+            1 | 2 => {
+                let a = read(&program, read(&program, pc + 1)?)?;
+                let b = read(&program, read(&program, pc + 2)?)?;
+                if op == 1 {
+                    a.checked_add(b)
+                } else {
+                    a.checked_mul(b)
+                }
+                .ok_or_else(|| Error::invalid(format!("overflow at {}", pc)))?
+            }
+            99 => break,
+            _ => return Err(Error::invalid(format!("unknown opcode {} at {}", op, pc))),
+        };
+        let dest = read(&program, pc + 3)?;
+        *program
+            .get_mut(dest)
+            .ok_or_else(|| Error::invalid(format!("address {} out of bounds", dest)))? = value;
+        pc += 4;
+    }
+
+    Ok(program)
+}
+
+fn parse(input: &str) -> Result<Vec<usize>> {
+    Ok(parse::complete(separated(integer(), tag(",")), input)?)
+}
+
+fn part_one(input: &str, noun: usize, verb: usize) -> Result<Vec<usize>> {
+    execute(parse(input)?, noun, verb)
+}
+
+fn part_two(input: &str) -> Result<usize> {
+    let target = 19_690_720;
+    let program = parse(input)?;
+    (0..100)
+        .flat_map(|noun| (0..100).map(move |verb| (noun, verb)))
+        .find(|&(noun, verb)| {
+            execute(program.clone(), noun, verb).is_ok_and(|memory| memory[0] == target)
+        })
+        .map(|(noun, verb)| 100 * noun + verb)
+        .ok_or_else(|| Error::invalid(format!("no noun and verb produce {}", target)))
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2019, 2)
+        .part(|input| Ok(part_one(input, 12, 2)?[0]))
+        .part(part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_examples() {
+        assert_eq!(part_one("1,0,0,0,99", 0, 0), Ok(vec![2, 0, 0, 0, 99]));
+        assert_eq!(part_one("2,3,0,3,99", 3, 0), Ok(vec![2, 3, 0, 6, 99]));
+        assert_eq!(
+            part_one("2,4,4,5,99,0", 4, 4),
+            Ok(vec![2, 4, 4, 5, 99, 9801])
+        );
+        assert_eq!(
+            part_one("1,1,1,4,99,5,6,0,99", 1, 1),
+            Ok(vec![30, 1, 1, 4, 2, 5, 6, 0, 99])
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_one("1,0,x,0,99", 0, 0).is_err());
+        assert!(part_one("1,0,0", 0, 0).is_err());
+        assert!(part_one("3,0,0,0,99", 0, 0).is_err());
+        assert!(part_two("1,0").is_err());
+    }
+}
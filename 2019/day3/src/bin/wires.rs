@@ -0,0 +1,43 @@
+use aoc_2019_day3::{parse, render};
+use std::{env, error::Error, fs, process::ExitCode};
+
+const USAGE: &str = "usage: wires [--ascii] <input>";
+
+fn draw(args: impl Iterator<Item = String>) -> Result<String, Box<dyn Error>> {
+    let mut ascii = false;
+    let mut paths = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--ascii" => ascii = true,
+            flag if flag.starts_with("--") => return Err(format!("unknown flag `{}`", flag).into()),
+            _ => paths.push(arg),
+        }
+    }
+    let path = match paths.as_slice() {
+        [path] => path,
+        _ => return Err("expected an input path".into()),
+    };
+    let input = fs::read_to_string(path).map_err(|err| format!("{}: {}", path, err))?;
+    let wires = parse(&input).map_err(|err| match err {
+        aoc::Error::Parse(_) => format!("{}:{}", path, err),
+        aoc::Error::Invalid(_) => format!("{}: {}", path, err),
+    })?;
+    Ok(if ascii {
+        render::ascii(&wires)?
+    } else {
+        render::svg(&wires)
+    })
+}
+
+fn main() -> ExitCode {
+    match draw(env::args().skip(1)) {
+        Ok(diagram) => {
+            print!("{}", diagram);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}\n{}", err, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
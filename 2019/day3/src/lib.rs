@@ -0,0 +1,200 @@
+use aoc::{
+    parse::{self, integer, keyword, lines, map, separated, tag, Input, PResult, Parser},
+    Error, Result, Solution,
+};
+use std::str::FromStr;
+
+pub mod render;
+pub mod wires;
+
+use wires::Wire;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Right,
+    Left,
+    Down,
+    Up,
+}
+
+impl Direction {
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Right => (1, 0),
+            Direction::Left => (-1, 0),
+            Direction::Up => (0, 1),
+            Direction::Down => (0, -1),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub direction: Direction,
+    pub distance: usize,
+}
+
+fn movement(input: Input) -> PResult<Move> {
+    let (direction, input) = keyword([
+        ("R", Direction::Right),
+        ("L", Direction::Left),
+        ("D", Direction::Down),
+        ("U", Direction::Up),
+    ])
+    .parse(input)?;
+    let (distance, input) = integer().parse(input)?;
+    Ok((
+        Move {
+            direction,
+            distance,
+        },
+        input,
+    ))
+}
+
+impl FromStr for Move {
+    type Err = parse::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(movement, s)
+    }
+}
+
+fn moves(input: Input) -> PResult<Vec<Move>> {
+    separated(movement, tag(",")).parse(input)
+}
+
+impl FromStr for Wire {
+    type Err = parse::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(map(moves, |moves| Wire::new(&moves)), s)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Wire>> {
+    let wires = parse::complete(lines(map(moves, |moves| Wire::new(&moves))), input)?;
+    if wires.len() < 2 {
+        return Err(Error::invalid("expected at least two wires"));
+    }
+    Ok(wires)
+}
+
+fn part_one(input: &str) -> Result<usize> {
+    wires::closest(&parse(input)?)
+        .map(|crossing| crossing.distance())
+        .ok_or_else(|| Error::invalid("wires don't cross"))
+}
+
+fn part_two(input: &str) -> Result<usize> {
+    wires::fewest_steps(&parse(input)?)
+        .map(|crossing| crossing.combined_steps())
+        .ok_or_else(|| Error::invalid("wires don't cross"))
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2019, 3).part(part_one).part(part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use wires::Crossing;
+
+    #[test]
+    fn part_one_examples() {
+        assert_eq!(part_one("R8,U5,L5,D3\nU7,R6,D4,L4"), Ok(6));
+        assert_eq!(
+            part_one("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83"),
+            Ok(159)
+        );
+        assert_eq!(
+            part_one(
+                "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7"
+            ),
+            Ok(135)
+        );
+    }
+
+    #[test]
+    fn part_two_examples() {
+        assert_eq!(
+            part_two("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83"),
+            Ok(610)
+        );
+        assert_eq!(
+            part_two(
+                "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7"
+            ),
+            Ok(410)
+        );
+    }
+
+    /// The points every wire visits, walking them one step at a time.
+    fn walk(wires: &[Vec<Move>]) -> Vec<Crossing> {
+        let paths = wires
+            .iter()
+            .map(|moves| {
+                moves
+                    .iter()
+                    .flat_map(|x| std::iter::repeat_n(x.direction.delta(), x.distance))
+                    .scan((0, 0), |position, (dx, dy)| {
+                        *position = (position.0 + dx, position.1 + dy);
+                        Some(*position)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut common = paths
+            .iter()
+            .map(|path| path.iter().copied().collect::<HashSet<_>>())
+            .reduce(|common, path| &common & &path)
+            .unwrap_or_default()
+            .into_iter()
+            .collect::<Vec<_>>();
+        common.sort();
+        common
+            .into_iter()
+            .map(|position| Crossing {
+                position,
+                steps: paths
+                    .iter()
+                    .map(|path| path.iter().position(|&x| x == position).unwrap() + 1)
+                    .collect(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn cross_check() {
+        for input in [
+            "R8,U5,L5,D3\nU7,R6,D4,L4",
+            "R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83",
+            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7",
+            // Overlapping segments, in both directions.
+            "R10,U3,L10\nR4,R0,R4,U3,L2,D6\nU3,R10,D4,L7,U1",
+            // Wires returning to the central port and crossing themselves.
+            "R5,U2,L5,D2,R3,D1\nU1,R1,D1,L1,R6,U2",
+            "U2,L2\nL2,U2,R2,D2",
+        ] {
+            let moves = input
+                .lines()
+                .map(|line| parse::complete(moves, line).unwrap())
+                .collect::<Vec<_>>();
+            let wires = input
+                .lines()
+                .map(|line| line.parse().unwrap())
+                .collect::<Vec<Wire>>();
+            let crossings = wires::crossings(&wires);
+            assert!(!crossings.is_empty(), "{}", input);
+            assert_eq!(crossings, walk(&moves), "{}", input);
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_one("R8,X5\nU7").is_err());
+        assert!(part_one("R8,U5").is_err());
+        assert!(part_two("R8,U5\nL5,D").is_err());
+        assert!(part_two("R8\nL8").is_err());
+    }
+}
@@ -0,0 +1,209 @@
+//! Diagrams of wires, with `o` for the central port, `X` for crossings and `*`
+//! for the crossing closest to the port.
+
+use super::wires::{self, Crossing, Point, Segment, Wire};
+use aoc::{Error, Result};
+use std::fmt::Write;
+
+/// The most cells `ascii` draws, about a megabyte of output.
+pub const MAX_CELLS: usize = 1 << 20;
+
+const COLORS: [&str; 6] = [
+    "#1f77b4", "#ff7f0e", "#2ca02c", "#9467bd", "#8c564b", "#e377c2",
+];
+
+/// The points of `segment` after its start.
+fn points(segment: &Segment) -> impl Iterator<Item = Point> {
+    let (dx, dy) = (
+        (segment.end.0 - segment.start.0).signum(),
+        (segment.end.1 - segment.start.1).signum(),
+    );
+    let length = (segment.end.0 - segment.start.0 + segment.end.1 - segment.start.1).abs();
+    let start = segment.start;
+    (1..=length).map(move |step| (start.0 + dx * step, start.1 + dy * step))
+}
+
+/// The smallest and largest coordinates, including the central port.
+fn bounds(wires: &[Wire]) -> (Point, Point) {
+    wires
+        .iter()
+        .flat_map(Wire::segments)
+        .map(|segment| segment.end)
+        .fold(((0, 0), (0, 0)), |(min, max), (x, y)| {
+            ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+        })
+}
+
+/// Describes the closest crossing and the one with the fewest combined steps.
+fn legend(wires: &[Wire]) -> Vec<String> {
+    let describe = |name: &str, crossing: Option<Crossing>| match crossing {
+        Some(crossing) => format!(
+            "{}: {:?} at distance {} in {} = {} steps",
+            name,
+            crossing.position,
+            crossing.distance(),
+            crossing
+                .steps
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(" + "),
+            crossing.combined_steps()
+        ),
+        None => format!("{}: none", name),
+    };
+    vec![
+        describe("closest", wires::closest(wires)),
+        describe("fewest steps", wires::fewest_steps(wires)),
+    ]
+}
+
+/// Draws the wires on a grid with `-` and `|`, and `+` where they turn, followed
+/// by a legend. Only meant for small inputs, as the grid spans every point, so
+/// grids of more than `MAX_CELLS` are rejected.
+pub fn ascii(wires: &[Wire]) -> Result<String> {
+    let ((min_x, min_y), (max_x, max_y)) = bounds(wires);
+    let width = max_x.abs_diff(min_x) + 1;
+    let height = max_y.abs_diff(min_y) + 1;
+    if width
+        .checked_mul(height)
+        .is_none_or(|cells| cells > MAX_CELLS)
+    {
+        return Err(Error::invalid(format!(
+            "a {}x{} grid is too large to draw, try SVG instead",
+            width, height
+        )));
+    }
+    let mut grid = vec![vec!['.'; width]; height];
+    // Rows go from the top down, while up is positive.
+    let mut set = |(x, y): Point, char| grid[(max_y - y) as usize][(x - min_x) as usize] = char;
+
+    for wire in wires {
+        for (idx, segment) in wire.segments().iter().enumerate() {
+            let line = if segment.start.1 == segment.end.1 {
+                '-'
+            } else {
+                '|'
+            };
+            for point in points(segment) {
+                set(point, line);
+            }
+            if idx + 1 < wire.segments().len() {
+                set(segment.end, '+');
+            }
+        }
+    }
+    for crossing in wires::crossings(wires) {
+        set(crossing.position, 'X');
+    }
+    if let Some(crossing) = wires::closest(wires) {
+        set(crossing.position, '*');
+    }
+    set((0, 0), 'o');
+
+    let mut output = grid
+        .into_iter()
+        .map(|row| row.into_iter().chain(['\n']).collect::<String>())
+        .collect::<String>();
+    for line in legend(wires) {
+        let _ = writeln!(output, "{}", line);
+    }
+    Ok(output)
+}
+
+/// Draws the wires as coloured polylines, with up at the top, and labels the
+/// closest crossing with its steps.
+pub fn svg(wires: &[Wire]) -> String {
+    let ((min_x, min_y), (max_x, max_y)) = bounds(wires);
+    let extent = (max_x - min_x).max(max_y - min_y).max(1) as f64;
+    let margin = extent / 20.;
+    let radius = extent / 200.;
+
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\" font-size=\"{}\">",
+        min_x as f64 - margin,
+        -max_y as f64 - margin,
+        (max_x - min_x) as f64 + 2. * margin,
+        (max_y - min_y) as f64 + 2. * margin,
+        extent / 50.
+    );
+    for (wire, color) in wires.iter().zip(COLORS.iter().cycle()) {
+        let points = std::iter::once((0, 0))
+            .chain(wire.segments().iter().map(|segment| segment.end))
+            .map(|(x, y)| format!("{},{}", x, -y))
+            .collect::<Vec<_>>();
+        let _ = writeln!(
+            output,
+            "  <polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"1\" vector-effect=\"non-scaling-stroke\"/>",
+            points.join(" "),
+            color
+        );
+    }
+    for crossing in wires::crossings(wires) {
+        let (x, y) = crossing.position;
+        let _ = writeln!(
+            output,
+            "  <circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"red\"/>",
+            x, -y, radius
+        );
+    }
+    if let Some(crossing) = wires::closest(wires) {
+        let (x, y) = crossing.position;
+        let _ = writeln!(
+            output,
+            "  <circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"none\" stroke=\"red\" vector-effect=\"non-scaling-stroke\"/>",
+            x,
+            -y,
+            3. * radius
+        );
+    }
+    let _ = writeln!(
+        output,
+        "  <circle cx=\"0\" cy=\"0\" r=\"{}\" fill=\"black\"/>",
+        2. * radius
+    );
+    for (idx, line) in legend(wires).into_iter().enumerate() {
+        let _ = writeln!(
+            output,
+            "  <text x=\"{}\" y=\"{}\">{}</text>",
+            min_x as f64 - margin / 2.,
+            -max_y as f64 - margin / 2. + idx as f64 * extent / 40.,
+            line
+        );
+    }
+    output.push_str("</svg>\n");
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagrams() {
+        let wires = ["R8,U5,L5,D3", "U7,R6,D4,L4"].map(|x| x.parse::<Wire>().unwrap());
+        assert_eq!(
+            ascii(&wires).as_deref(),
+            Ok("\
++-----+..
+|.....|..
+|..+--X-+
+|..|..|.|
+|.-*--+.|
+|..|....|
+|.......|
+o-------+
+closest: (3, 3) at distance 6 in 20 + 20 = 40 steps
+fewest steps: (6, 5) at distance 11 in 15 + 15 = 30 steps
+")
+        );
+        let wide = ["R2000,U1", "U2000"].map(|x| x.parse::<Wire>().unwrap());
+        assert!(ascii(&wide).is_err());
+        assert!(svg(&wide).starts_with("<svg"));
+        let svg = svg(&wires);
+        assert_eq!(svg.matches("<polyline").count(), 2);
+        assert!(svg.contains("<circle cx=\"3\" cy=\"-3\" r=\"0.04\" fill=\"red\"/>"));
+    }
+}
@@ -0,0 +1,228 @@
+//! Wires as horizontal and vertical segments, crossed with a sweep line instead
+//! of walking every step.
+
+use super::Move;
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::RangeInclusive,
+};
+
+pub type Point = (isize, isize);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Segment {
+    pub start: Point,
+    pub end: Point,
+    /// Steps along the wire before `start`.
+    pub steps: usize,
+}
+
+impl Segment {
+    fn is_horizontal(&self) -> bool {
+        self.start.1 == self.end.1
+    }
+
+    fn xs(&self) -> RangeInclusive<isize> {
+        self.start.0.min(self.end.0)..=self.start.0.max(self.end.0)
+    }
+
+    fn ys(&self) -> RangeInclusive<isize> {
+        self.start.1.min(self.end.1)..=self.start.1.max(self.end.1)
+    }
+
+    /// Steps along the wire to reach `point` on this segment.
+    fn steps_to(&self, point: Point) -> usize {
+        self.steps
+            + (point.0 - self.start.0).unsigned_abs()
+            + (point.1 - self.start.1).unsigned_abs()
+    }
+
+    fn transpose(self) -> Self {
+        Self {
+            start: (self.start.1, self.start.0),
+            end: (self.end.1, self.end.0),
+            steps: self.steps,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Wire {
+    segments: Vec<Segment>,
+}
+
+impl Wire {
+    pub fn new(moves: &[Move]) -> Self {
+        let mut start = (0, 0);
+        let mut steps = 0;
+        let segments = moves
+            .iter()
+            .filter(|movement| movement.distance != 0)
+            .map(|movement| {
+                let (dx, dy) = movement.direction.delta();
+                let distance = movement.distance as isize;
+                let segment = Segment {
+                    start,
+                    end: (start.0 + dx * distance, start.1 + dy * distance),
+                    steps,
+                };
+                start = segment.end;
+                steps += movement.distance;
+                segment
+            })
+            .collect();
+        Self { segments }
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+}
+
+/// A point on every wire, with the fewest steps each wire takes to reach it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Crossing {
+    pub position: Point,
+    pub steps: Vec<usize>,
+}
+
+impl Crossing {
+    /// The Manhattan distance to the central port.
+    pub fn distance(&self) -> usize {
+        self.position.0.unsigned_abs() + self.position.1.unsigned_abs()
+    }
+
+    pub fn combined_steps(&self) -> usize {
+        self.steps.iter().sum()
+    }
+}
+
+/// All points on every wire, ordered by position. The central port only counts
+/// when a wire returns to it.
+pub fn crossings(wires: &[Wire]) -> Vec<Crossing> {
+    let mut found = HashMap::<Point, Vec<Option<usize>>>::new();
+    let mut record = |point: Point, wire: usize, steps: usize| {
+        if steps != 0 {
+            let fewest = &mut found
+                .entry(point)
+                .or_insert_with(|| vec![None; wires.len()])[wire];
+            *fewest = Some(fewest.map_or(steps, |fewest| fewest.min(steps)));
+        }
+    };
+
+    let (horizontal, vertical): (Vec<_>, Vec<_>) = wires
+        .iter()
+        .enumerate()
+        .flat_map(|(wire, segments)| segments.segments.iter().map(move |&x| (wire, x)))
+        .partition(|(_, segment)| segment.is_horizontal());
+    perpendicular(&horizontal, &vertical, &mut record);
+    collinear(&horizontal, &mut record);
+    let vertical = vertical
+        .into_iter()
+        .map(|(wire, segment)| (wire, segment.transpose()))
+        .collect::<Vec<_>>();
+    collinear(&vertical, &mut |(y, x), wire, steps| {
+        record((x, y), wire, steps)
+    });
+
+    let mut crossings = found
+        .into_iter()
+        .filter_map(|(position, steps)| {
+            Some(Crossing {
+                position,
+                steps: steps.into_iter().collect::<Option<_>>()?,
+            })
+        })
+        .collect::<Vec<_>>();
+    crossings.sort_by_key(|crossing| crossing.position);
+    crossings
+}
+
+/// Sweeps from left to right over the vertical segments, keeping the
+/// horizontal ones that span the current column ordered by height.
+fn perpendicular(
+    horizontal: &[(usize, Segment)],
+    vertical: &[(usize, Segment)],
+    record: &mut impl FnMut(Point, usize, usize),
+) {
+    // Within a column, segments start before the crossings and end after.
+    const START: u8 = 0;
+    const CROSS: u8 = 1;
+    const END: u8 = 2;
+
+    let mut events = horizontal
+        .iter()
+        .flat_map(|&(wire, segment)| {
+            [
+                (*segment.xs().start(), START, wire, segment),
+                (*segment.xs().end(), END, wire, segment),
+            ]
+        })
+        .chain(
+            vertical
+                .iter()
+                .map(|&(wire, segment)| (segment.start.0, CROSS, wire, segment)),
+        )
+        .collect::<Vec<_>>();
+    events.sort_by_key(|&(x, event, ..)| (x, event));
+
+    let mut active = BTreeMap::<isize, Vec<(usize, Segment)>>::new();
+    for (x, event, wire, segment) in events {
+        match event {
+            START => active
+                .entry(segment.start.1)
+                .or_default()
+                .push((wire, segment)),
+            END => {
+                if let Some(row) = active.get_mut(&segment.start.1) {
+                    row.retain(|&x| x != (wire, segment));
+                }
+            }
+            _ => {
+                for (&y, row) in active.range(segment.ys()) {
+                    for &(other, across) in row.iter().filter(|(other, _)| *other != wire) {
+                        record((x, y), wire, segment.steps_to((x, y)));
+                        record((x, y), other, across.steps_to((x, y)));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Records every point where horizontal segments of different wires overlap.
+fn collinear(horizontal: &[(usize, Segment)], record: &mut impl FnMut(Point, usize, usize)) {
+    let mut rows = BTreeMap::<isize, Vec<(usize, Segment)>>::new();
+    for &(wire, segment) in horizontal {
+        rows.entry(segment.start.1)
+            .or_default()
+            .push((wire, segment));
+    }
+    for (y, mut row) in rows {
+        row.sort_by_key(|(_, segment)| *segment.xs().start());
+        let mut open: Vec<(usize, Segment)> = Vec::new();
+        for (wire, segment) in row {
+            let xs = segment.xs();
+            open.retain(|(_, other)| other.xs().end() >= xs.start());
+            for &(other, along) in open.iter().filter(|(other, _)| *other != wire) {
+                for x in *xs.start()..=*xs.end().min(along.xs().end()) {
+                    record((x, y), wire, segment.steps_to((x, y)));
+                    record((x, y), other, along.steps_to((x, y)));
+                }
+            }
+            open.push((wire, segment));
+        }
+    }
+}
+
+/// The crossing closest to the central port.
+pub fn closest(wires: &[Wire]) -> Option<Crossing> {
+    crossings(wires).into_iter().min_by_key(Crossing::distance)
+}
+
+/// The crossing the wires reach in the fewest combined steps.
+pub fn fewest_steps(wires: &[Wire]) -> Option<Crossing> {
+    crossings(wires)
+        .into_iter()
+        .min_by_key(Crossing::combined_steps)
+}
@@ -0,0 +1,233 @@
+use aoc::{
+    parse::{self, integer, keyword, pair, terminated},
+    Error, Result, Solution,
+};
+use std::{collections::HashMap, ops::Range};
+
+/// Which repeated digits make a password valid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Rule {
+    /// Some digit is repeated, in a run of any length.
+    Pair,
+    /// Some digit is repeated exactly twice in a row.
+    ExactPair,
+}
+
+/// The digits so far: the last one, the length of its run (capped at 3, 0 before
+/// the first digit) and whether the rule is satisfied by an earlier run.
+#[derive(Copy, Clone)]
+struct State {
+    last: usize,
+    run: usize,
+    satisfied: bool,
+}
+
+impl State {
+    /// Leading zeros are excluded by starting at 1.
+    const START: State = State {
+        last: 1,
+        run: 0,
+        satisfied: false,
+    };
+
+    fn push(self, digit: usize, rule: Rule) -> Option<Self> {
+        if digit < self.last {
+            return None;
+        }
+        Some(if self.run != 0 && digit == self.last {
+            let run = (self.run + 1).min(3);
+            State {
+                run,
+                satisfied: self.satisfied || (rule == Rule::Pair && run == 2),
+                ..self
+            }
+        } else {
+            State {
+                last: digit,
+                run: 1,
+                satisfied: self.satisfied || (rule == Rule::ExactPair && self.run == 2),
+            }
+        })
+    }
+
+    fn accepts(self, rule: Rule) -> bool {
+        self.satisfied || (rule == Rule::ExactPair && self.run == 2)
+    }
+}
+
+/// Counts the valid passwords of `length` digits in `range`, without
+/// enumerating them.
+pub fn count(range: Range<u64>, length: u32, rule: Rule) -> Result<u64> {
+    let (min, max) = match (length.checked_sub(1), 10u64.checked_pow(length)) {
+        (Some(shorter), Some(max)) => (10u64.pow(shorter), max),
+        _ => {
+            return Err(Error::invalid(format!(
+                "can't count passwords of {} digits",
+                length
+            )))
+        }
+    };
+    let (start, end) = (range.start.max(min), range.end.min(max));
+    if start >= end {
+        return Ok(0);
+    }
+    let mut memo = HashMap::new();
+    Ok(below(end, length, rule, &mut memo) - below(start, length, rule, &mut memo))
+}
+
+/// Counts the valid passwords of `length` digits below `bound`, which has at
+/// most `length + 1` digits.
+fn below(bound: u64, length: u32, rule: Rule, memo: &mut Memo) -> u64 {
+    let length = length as usize;
+    let digits = bound
+        .to_string()
+        .bytes()
+        .map(|digit| usize::from(digit - b'0'))
+        .collect::<Vec<_>>();
+    if digits.len() > length {
+        return completions(length, State::START, rule, memo);
+    }
+
+    let mut total = 0;
+    let mut state = State::START;
+    for (idx, &bound) in digits.iter().enumerate() {
+        total += (0..bound)
+            .filter_map(|digit| state.push(digit, rule))
+            .map(|next| completions(length - idx - 1, next, rule, memo))
+            .sum::<u64>();
+        match state.push(bound, rule) {
+            Some(next) => state = next,
+            None => break,
+        }
+    }
+    total
+}
+
+type Memo = HashMap<(usize, usize, usize, bool), u64>;
+
+/// Counts the ways to add `remaining` digits after `state` to get a valid password.
+fn completions(remaining: usize, state: State, rule: Rule, memo: &mut Memo) -> u64 {
+    if remaining == 0 {
+        return u64::from(state.accepts(rule));
+    }
+    let key = (remaining, state.last, state.run, state.satisfied);
+    if let Some(&count) = memo.get(&key) {
+        return count;
+    }
+    let count = (0..10)
+        .filter_map(|digit| state.push(digit, rule))
+        .map(|next| completions(remaining - 1, next, rule, memo))
+        .sum();
+    memo.insert(key, count);
+    count
+}
+
+fn parse(input: &str) -> Result<Range<u64>> {
+    let separator = keyword([("-", ()), ("\n", ())]);
+    let (start, end) = parse::complete(pair(terminated(integer(), separator), integer()), input)?;
+    Ok(start..end)
+}
+
+fn part_one(input: &str) -> Result<u64> {
+    count(parse(input)?, 6, Rule::Pair)
+}
+
+fn part_two(input: &str) -> Result<u64> {
+    count(parse(input)?, 6, Rule::ExactPair)
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2019, 4).part(part_one).part(part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_valid(input: usize) -> bool {
+        let chars: Vec<char> = input.to_string().chars().collect();
+        let mut zip = chars.iter().take(5).zip(chars.iter().skip(1));
+
+        chars.len() == 6
+            && zip
+                .clone()
+                .map(|(x, y)| (x.to_digit(10).unwrap(), y.to_digit(10).unwrap()))
+                .all(|(x, y)| x <= y)
+            && zip.any(|(x, y)| x == y)
+    }
+
+    fn is_valid2(input: usize) -> bool {
+        let chars: Vec<char> = input.to_string().chars().collect();
+        is_valid(input)
+            && chars
+                .iter()
+                .enumerate()
+                .map(|(i, x)| chars.iter().skip(i).take_while(move |y| y == &x).count())
+                .fold(Vec::new(), |mut acc, x| {
+                    if acc.last() == Some(&1) || x == 1 || acc.is_empty() {
+                        acc.push(x)
+                    }
+                    acc
+                })
+                .into_iter()
+                .any(|x| x == 2)
+    }
+
+    fn brute_force(range: Range<u64>, rule: Rule) -> u64 {
+        let valid = match rule {
+            Rule::Pair => is_valid,
+            Rule::ExactPair => is_valid2,
+        };
+        range.filter(|&x| valid(x as usize)).count() as u64
+    }
+
+    #[test]
+    fn part_one_examples() {
+        assert!(is_valid(111_111));
+        assert!(!is_valid(223_450));
+        assert!(!is_valid(123_789));
+    }
+
+    #[test]
+    fn part_two_examples() {
+        assert!(is_valid2(112_233));
+        assert!(!is_valid2(123_444));
+        assert!(is_valid2(111_122));
+    }
+
+    #[test]
+    fn counts() {
+        for rule in [Rule::Pair, Rule::ExactPair] {
+            for range in [
+                0..100_100,
+                134_564..585_159,
+                111_122..111_123,
+                223_450..223_451,
+                999_000..1_000_100,
+            ] {
+                assert_eq!(
+                    count(range.clone(), 6, rule),
+                    Ok(brute_force(range.clone(), rule)),
+                    "{:?} {:?}",
+                    rule,
+                    range
+                );
+            }
+        }
+        // Every non-decreasing number of 18 digits repeats one, as there are
+        // only 9 digits after leading zeros.
+        assert_eq!(count(0..u64::MAX, 18, Rule::Pair), Ok(1_562_275));
+        assert_eq!(count(0..10, 1, Rule::Pair), Ok(0));
+        assert_eq!(count(0..100, 2, Rule::ExactPair), Ok(9));
+        assert!(count(0..u64::MAX, 0, Rule::Pair).is_err());
+        assert!(count(0..u64::MAX, 20, Rule::Pair).is_err());
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(part_one("111110-111112"), Ok(1));
+        assert_eq!(part_two("111121\n111123"), Ok(1));
+        assert!(part_one("111110").is_err());
+        assert!(part_two("111110-x").is_err());
+    }
+}
@@ -0,0 +1,282 @@
+use aoc::{
+    graph::{Graph, Lca, NodeId},
+    parse::{self, lines, pair, tag, take_while1, terminated, Input, PResult, Parser},
+    Error, Result, Solution,
+};
+use std::fmt::Write;
+
+fn object<'a>() -> impl Parser<'a, &'a str> {
+    take_while1("an object", char::is_alphanumeric)
+}
+
+fn orbit<'a>(input: Input<'a>) -> PResult<'a, (&'a str, &'a str)> {
+    pair(terminated(object(), tag(")")), object()).parse(input)
+}
+
+/// Objects in orbit around each other, forming a forest with an object's center
+/// as its parent.
+pub struct OrbitMap<'a> {
+    graph: Graph<&'a str>,
+    center: Vec<Option<NodeId>>,
+    depth: Vec<usize>,
+    size: Vec<usize>,
+    lca: Lca,
+}
+
+impl<'a> OrbitMap<'a> {
+    pub fn parse(input: &'a str) -> Result<Self> {
+        Self::new(parse::complete(lines(orbit), input)?)
+    }
+
+    /// Builds the map from `(center, object)` pairs, rejecting objects with
+    /// multiple centers and cycles.
+    pub fn new(orbits: impl IntoIterator<Item = (&'a str, &'a str)>) -> Result<Self> {
+        let mut graph = Graph::new();
+        let mut center = Vec::new();
+        for (parent, object) in orbits {
+            let (parent, object) = graph.add_edge(parent, object, ());
+            center.resize(graph.len(), None);
+            if let Some(other) = center[object.index()].replace(parent) {
+                return Err(Error::invalid(format!(
+                    "{} orbits both {} and {}",
+                    graph.node(object),
+                    graph.node(other),
+                    graph.node(parent)
+                )));
+            }
+        }
+
+        let order = graph.topological_order().map_err(|cycle| {
+            let objects = cycle
+                .0
+                .iter()
+                .chain(cycle.0.first())
+                .map(|&id| *graph.node(id))
+                .collect::<Vec<_>>();
+            Error::invalid(format!("orbits form a cycle: {}", objects.join(" -> ")))
+        })?;
+        let mut depth = vec![0; graph.len()];
+        for &id in &order {
+            for object in graph.neighbours(id) {
+                depth[object.index()] = depth[id.index()] + 1;
+            }
+        }
+        let mut size = vec![0; graph.len()];
+        for &id in order.iter().rev() {
+            size[id.index()] = graph
+                .neighbours(id)
+                .map(|object| 1 + size[object.index()])
+                .sum();
+        }
+        let roots = graph.ids().filter(|id| center[id.index()].is_none());
+        let lca = Lca::forest(&graph, roots);
+
+        Ok(Self {
+            graph,
+            center,
+            depth,
+            size,
+            lca,
+        })
+    }
+
+    fn id(&self, object: &str) -> Result<NodeId> {
+        self.graph
+            .id(object)
+            .ok_or_else(|| Error::invalid(format!("missing {}", object)))
+    }
+
+    /// The object `object` orbits directly, if any.
+    pub fn center(&self, object: &str) -> Result<Option<&'a str>> {
+        Ok(self.center[self.id(object)?.index()].map(|id| *self.graph.node(id)))
+    }
+
+    /// The number of direct and indirect orbits of `object`.
+    pub fn depth(&self, object: &str) -> Result<usize> {
+        Ok(self.depth[self.id(object)?.index()])
+    }
+
+    /// The number of objects orbiting `object` directly or indirectly.
+    pub fn subtree_size(&self, object: &str) -> Result<usize> {
+        Ok(self.size[self.id(object)?.index()])
+    }
+
+    /// The direct and indirect orbits of all objects around `center`.
+    pub fn total_orbits(&self, center: &str) -> Result<usize> {
+        let center = self.id(center)?;
+        Ok(self
+            .graph
+            .dfs(center)
+            .map(|id| self.depth[id.index()] - self.depth[center.index()])
+            .sum())
+    }
+
+    fn disconnected(from: &str, to: &str) -> Error {
+        Error::invalid(format!("{} and {} don't orbit a common center", from, to))
+    }
+
+    /// The objects from `object` up to its center `ancestor`, both included.
+    fn ancestors(&self, mut object: NodeId, ancestor: NodeId) -> Vec<NodeId> {
+        let mut objects = vec![object];
+        while object != ancestor {
+            object = self.center[object.index()].expect("ancestor above object");
+            objects.push(object);
+        }
+        objects
+    }
+
+    /// The objects from `from` to `to`, both included, through their closest
+    /// common center.
+    pub fn path(&self, from: &str, to: &str) -> Result<Vec<&'a str>> {
+        let (a, b) = (self.id(from)?, self.id(to)?);
+        let common = self
+            .lca
+            .lca(a, b)
+            .ok_or_else(|| Self::disconnected(from, to))?;
+        let mut down = self.ancestors(b, common);
+        down.pop();
+        Ok(self
+            .ancestors(a, common)
+            .into_iter()
+            .chain(down.into_iter().rev())
+            .map(|id| *self.graph.node(id))
+            .collect())
+    }
+
+    /// The orbital transfers to move from the center of `from` to the center of
+    /// `to`.
+    pub fn transfers(&self, from: &str, to: &str) -> Result<usize> {
+        let distance = self
+            .lca
+            .distance(self.id(from)?, self.id(to)?)
+            .ok_or_else(|| Self::disconnected(from, to))?;
+        distance
+            .checked_sub(2)
+            .ok_or_else(|| Error::invalid(format!("{} and {} orbit each other", from, to)))
+    }
+
+    /// The map in Graphviz DOT format, with an edge from each center to the
+    /// objects orbiting it.
+    pub fn dot(&self) -> String {
+        let mut output = String::from("digraph orbits {\n");
+        for center in self.graph.ids() {
+            for object in self.graph.neighbours(center) {
+                let _ = writeln!(
+                    output,
+                    "  \"{}\" -> \"{}\";",
+                    self.graph.node(center),
+                    self.graph.node(object)
+                );
+            }
+        }
+        output.push_str("}\n");
+        output
+    }
+}
+
+fn part_one(input: &str) -> Result<usize> {
+    OrbitMap::parse(input)?.total_orbits("COM")
+}
+
+fn part_two(input: &str) -> Result<usize> {
+    OrbitMap::parse(input)?.transfers("YOU", "SAN")
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2019, 6).part(part_one).part(part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_examples() {
+        assert_eq!(
+            part_one(
+                r#"COM)B
+B)C
+C)D
+D)E
+E)F
+B)G
+G)H
+D)I
+E)J
+J)K
+K)L"#
+            ),
+            Ok(42)
+        )
+    }
+
+    #[test]
+    fn part_two_examples() {
+        assert_eq!(
+            part_two(
+                r#"COM)B
+B)C
+C)D
+D)E
+E)F
+B)G
+G)H
+D)I
+E)J
+J)K
+K)L
+K)YOU
+I)SAN"#
+            ),
+            Ok(4)
+        )
+    }
+
+    #[test]
+    fn orbit_map() {
+        let map = OrbitMap::parse("COM)B\nB)C\nC)D\nB)G\nG)H").unwrap();
+        assert_eq!(map.depth("D"), Ok(3));
+        assert_eq!(map.center("COM"), Ok(None));
+        assert_eq!(map.center("H"), Ok(Some("G")));
+        assert_eq!(map.subtree_size("B"), Ok(4));
+        assert_eq!(map.subtree_size("H"), Ok(0));
+        assert_eq!(map.total_orbits("B"), Ok(6));
+        assert_eq!(map.path("D", "H"), Ok(vec!["D", "C", "B", "G", "H"]));
+        assert_eq!(map.path("COM", "C"), Ok(vec!["COM", "B", "C"]));
+        assert_eq!(map.path("C", "C"), Ok(vec!["C"]));
+        assert_eq!(map.transfers("D", "H"), Ok(2));
+        assert!(map.transfers("B", "C").is_err());
+        assert!(map.depth("X").is_err());
+        assert!(map.dot().contains("  \"G\" -> \"H\";\n"));
+
+        let forest = OrbitMap::parse("A)B\nC)D").unwrap();
+        assert!(forest.path("B", "D").is_err());
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            OrbitMap::parse("A)B\nC)B").err().map(|err| err.to_string()),
+            Some("B orbits both A and C".to_string())
+        );
+        assert_eq!(
+            OrbitMap::parse("COM)A\nA)B\nB)C\nC)A")
+                .err()
+                .map(|err| err.to_string()),
+            Some("A orbits both COM and C".to_string())
+        );
+        assert_eq!(
+            OrbitMap::parse("A)B\nB)C\nC)A")
+                .err()
+                .map(|err| err.to_string()),
+            Some("orbits form a cycle: A -> B -> C -> A".to_string())
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_one("COM)B\nB-C").is_err());
+        assert!(part_one("A)B").is_err());
+        assert!(part_two("COM)B\nB)YOU").is_err());
+    }
+}
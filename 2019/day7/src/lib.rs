@@ -0,0 +1,218 @@
+use aoc::{
+    parse::{self, integer, separated, tag},
+    Error, Result, Solution,
+};
+use aoc_2019_intcode::Intcode;
+use rayon::prelude::*;
+use std::ops::Range;
+
+fn part_one(program: &[isize], phase_setting: &[isize]) -> Result<isize> {
+    phase_setting.iter().try_fold(0isize, |acc, &phase| {
+        Intcode::new(program)
+            .run(vec![phase, acc])?
+            .output
+            .front()
+            .copied()
+            .ok_or_else(|| Error::invalid("amplifier produced no output"))
+    })
+}
+
+fn part_two(program: &[isize], phase_setting: &[isize]) -> Result<isize> {
+    // initialize
+    let len = phase_setting.len();
+    let mut amps: Vec<Intcode> = phase_setting
+        .iter()
+        .map(|&phase| {
+            let mut c = Intcode::new(program);
+            c.input.push_back(phase);
+            c
+        })
+        .collect();
+
+    // Start program
+    amps.first_mut()
+        .ok_or_else(|| Error::invalid("no amplifiers"))?
+        .run(vec![0])?;
+
+    // Feedback loop
+    for i in (0..len).cycle() {
+        if amps[i].done {
+            break;
+        } else if let Some(output) = amps[i].output.pop_front() {
+            amps[(i + 1) % len].run(vec![output])?;
+        } else {
+            return Err(Error::invalid("amplifier stalled without output"));
+        }
+    }
+    amps[0]
+        .input
+        .pop_front()
+        .ok_or_else(|| Error::invalid("no signal reached the thrusters"))
+}
+
+/// Every ordering of `length` distinct phases out of `phases`.
+fn sequences(phases: &[isize], length: usize) -> Vec<Vec<isize>> {
+    if length == 0 {
+        return vec![Vec::new()];
+    }
+    phases
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, &phase)| {
+            let rest = [&phases[..idx], &phases[idx + 1..]].concat();
+            sequences(&rest, length - 1)
+                .into_iter()
+                .map(move |sequence| std::iter::once(phase).chain(sequence).collect())
+        })
+        .collect()
+}
+
+/// A phase sequence with the signal it sends to the thrusters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Configuration {
+    pub phases: Vec<isize>,
+    pub signal: isize,
+}
+
+/// Tries every sequence of distinct `phases` for the `amplifiers` in parallel,
+/// and returns the one with the highest signal. Ties go to the first sequence
+/// in the order of `phases`.
+pub fn search(
+    program: &[isize],
+    amplifiers: usize,
+    phases: &[isize],
+    amplify: fn(&[isize], &[isize]) -> Result<isize>,
+) -> Result<Configuration> {
+    let phases = phases.iter().fold(Vec::new(), |mut distinct, &phase| {
+        if !distinct.contains(&phase) {
+            distinct.push(phase);
+        }
+        distinct
+    });
+    sequences(&phases, amplifiers)
+        .into_par_iter()
+        .map(|phases| {
+            Ok(Configuration {
+                signal: amplify(program, &phases)?,
+                phases,
+            })
+        })
+        .try_reduce_with(|a, b| Ok(if b.signal > a.signal { b } else { a }))
+        .unwrap_or_else(|| {
+            Err(Error::invalid(format!(
+                "no sequences of {} out of {} distinct phases",
+                amplifiers,
+                phases.len()
+            )))
+        })
+}
+
+fn max_signal(
+    input: &str,
+    phases: Range<isize>,
+    amplify: fn(&[isize], &[isize]) -> Result<isize>,
+) -> Result<isize> {
+    let program = parse::complete(separated(integer(), tag(",")), input)?;
+    let phases = phases.collect::<Vec<_>>();
+    Ok(search(&program, phases.len(), &phases, amplify)?.signal)
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2019, 7)
+        .part(|input| max_signal(input, 0..5, part_one))
+        .part(|input| max_signal(input, 5..10, part_two))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_examples() {
+        assert_eq!(
+            part_one(
+                &[3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0],
+                &[4, 3, 2, 1, 0]
+            ),
+            Ok(43210)
+        );
+        assert_eq!(
+            part_one(
+                &[
+                    3, 23, 3, 24, 1002, 24, 10, 24, 1002, 23, -1, 23, 101, 5, 23, 23, 1, 24, 23,
+                    23, 4, 23, 99, 0, 0
+                ],
+                &[0, 1, 2, 3, 4]
+            ),
+            Ok(54321)
+        );
+        assert_eq!(
+            part_one(
+                &[
+                    3, 31, 3, 32, 1002, 32, 10, 32, 1001, 31, -2, 31, 1007, 31, 0, 33, 1002, 33, 7,
+                    33, 1, 33, 31, 31, 1, 32, 31, 31, 4, 31, 99, 0, 0, 0
+                ],
+                &[1, 0, 4, 3, 2]
+            ),
+            Ok(65210)
+        );
+    }
+
+    #[test]
+    fn part_two_examples() {
+        assert_eq!(
+            part_two(
+                &[
+                    3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001,
+                    28, -1, 28, 1005, 28, 6, 99, 0, 0, 5
+                ],
+                &[9, 8, 7, 6, 5]
+            ),
+            Ok(139629729)
+        );
+        assert_eq!(
+            part_two(
+                &[
+                    3, 52, 1001, 52, -5, 52, 3, 53, 1, 52, 56, 54, 1007, 54, 5, 55, 1005, 55, 26,
+                    1001, 54, -5, 54, 1105, 1, 12, 1, 53, 54, 53, 1008, 54, 0, 55, 1001, 55, 1, 55,
+                    2, 53, 55, 53, 4, 53, 1001, 56, -1, 56, 1005, 56, 6, 99, 0, 0, 0, 0, 10
+                ],
+                &[9, 7, 8, 5, 6]
+            ),
+            Ok(18216)
+        );
+    }
+
+    #[test]
+    fn search() {
+        assert_eq!(sequences(&[0, 1, 2, 3], 2).len(), 12);
+        assert_eq!(sequences(&[0, 1], 3), Vec::<Vec<isize>>::new());
+
+        let program = [
+            3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
+        ];
+        assert_eq!(
+            super::search(&program, 5, &[0, 1, 2, 3, 4], part_one),
+            Ok(Configuration {
+                phases: vec![4, 3, 2, 1, 0],
+                signal: 43210
+            })
+        );
+        assert_eq!(
+            super::search(&program, 3, &[0, 1, 2, 3, 4, 5], part_one),
+            Ok(Configuration {
+                phases: vec![5, 4, 3],
+                signal: 543
+            })
+        );
+        assert!(super::search(&program, 6, &[0, 1, 2, 3, 4], part_one).is_err());
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(max_signal("3,15,3,16,x", 0..5, part_one).is_err());
+        assert!(max_signal("99", 0..5, part_one).is_err());
+        assert!(max_signal("3,0,3,1,99", 5..10, part_two).is_err());
+        assert!(part_two(&[3, 0, 99], &[]).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use aoc_2019_day8::sif::Image;
+use std::{env, error::Error, fs, path::Path, process::ExitCode};
+
+const USAGE: &str = "usage: sif [--size <width>x<height>] [--scale <n>] <input> \
+                     <output.png|ppm|svg|gif|directory>";
+
+fn export(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let (mut width, mut height, mut scale) = (25, 6, 10);
+    let mut paths = Vec::new();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for `{}`", arg));
+        match arg.as_str() {
+            "--size" => {
+                let size = value()?;
+                let (w, h) = size
+                    .split_once('x')
+                    .ok_or(format!("invalid size `{}`", size))?;
+                width = w.parse()?;
+                height = h.parse()?;
+            }
+            "--scale" => scale = value()?.parse()?,
+            flag if flag.starts_with("--") => return Err(format!("unknown flag `{}`", flag).into()),
+            _ => paths.push(arg),
+        }
+    }
+    if scale == 0 {
+        return Err("the scale must be at least 1".into());
+    }
+    let (input, output) = match paths.as_slice() {
+        [input, output] => (input, Path::new(output)),
+        _ => return Err("expected an input and an output path".into()),
+    };
+
+    let image = Image::decode(fs::read_to_string(input)?.trim_end(), width, height)?;
+    match output.extension().and_then(|x| x.to_str()) {
+        Some("png") => fs::write(output, image.png(scale)?)?,
+        Some("ppm") => fs::write(output, image.ppm(scale)?)?,
+        Some("svg") => fs::write(output, image.svg(scale)?)?,
+        Some("gif") => fs::write(output, image.gif(scale, 50)?)?,
+        Some(extension) => return Err(format!("unknown format `{}`", extension).into()),
+        None => {
+            fs::create_dir_all(output)?;
+            image.dump(scale, |idx| {
+                fs::File::create(output.join(format!("{:03}.ppm", idx)))
+            })?;
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match export(env::args().skip(1)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}\n{}", err, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
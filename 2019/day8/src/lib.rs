@@ -0,0 +1,71 @@
+use aoc::{ocr, Result, Solution};
+use std::collections::HashMap;
+
+pub mod sif;
+
+use sif::Image;
+
+fn part_one(input: &str, width: usize, height: usize) -> Result<usize> {
+    Ok(sif::layers(input, width, height)?
+        .iter()
+        .fold(vec![], |mut maps, layer| {
+            let mut map = HashMap::new();
+            for digit in layer.iter() {
+                map.entry(digit - b'0')
+                    .and_modify(|e| {
+                        *e += 1;
+                    })
+                    .or_insert(1);
+            }
+            maps.push(map);
+            maps
+        })
+        .iter()
+        .min_by_key(|x| x.get(&0))
+        .map(|x| x.get(&1).unwrap_or(&0) * x.get(&2).unwrap_or(&0))
+        .unwrap_or(0))
+}
+
+fn part_two(input: &str, width: usize, height: usize) -> Result<String> {
+    let image = Image::decode(input, width, height)?;
+    Ok(ocr::recognise(&image.lit()).unwrap_or_else(|| image.to_string()))
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2019, 8)
+        .part(|input| part_one(input, 25, 6))
+        .part(|input| part_two(input, 25, 6))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one("123456789012", 3, 2), Ok(1));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(
+            part_two("0222112222120000", 2, 2),
+            Ok("■□\n□■\n".to_string())
+        );
+    }
+
+    #[test]
+    fn part_two_letters() {
+        let pixels = ["1001", "1001", "1111", "1001", "1001", "1001"].concat();
+        let layers = format!("{}{}", pixels.replace('0', "2"), "0".repeat(24));
+        assert_eq!(part_two(&layers, 4, 6), Ok("H".to_string()));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_one("12345678901", 3, 2).is_err());
+        assert!(part_one("12345x789012", 3, 2).is_err());
+        assert!(part_one("123456", 0, 2).is_err());
+        assert!(part_two("0222112222120003", 2, 2).is_err());
+    }
+}
@@ -0,0 +1,327 @@
+//! The Space Image Format: layers of `width` by `height` digits, where `0` is
+//! black, `1` is white and `2` is transparent, stacked front to back.
+
+use aoc::{parse, Error, Result};
+use std::{
+    convert::TryFrom,
+    fmt::{self, Write as _},
+    io::{self, Write},
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Pixel {
+    Black,
+    White,
+    Transparent,
+}
+
+impl Pixel {
+    fn digit(self) -> char {
+        match self {
+            Self::Black => '0',
+            Self::White => '1',
+            Self::Transparent => '2',
+        }
+    }
+
+    fn rgba(self) -> [u8; 4] {
+        match self {
+            Self::Black => [0, 0, 0, 255],
+            Self::White => [255, 255, 255, 255],
+            Self::Transparent => [128, 128, 128, 0],
+        }
+    }
+}
+
+/// Splits the digit stream in layers of `width * height` digits.
+pub fn layers(input: &str, width: usize, height: usize) -> Result<Vec<&[u8]>> {
+    let digits = parse::complete(parse::take_while1("a digit", |x| x.is_ascii_digit()), input)?;
+    let size = width * height;
+    if size == 0 {
+        return Err(Error::invalid("image dimensions must be positive"));
+    }
+    let incomplete = digits.len() % size;
+    if incomplete != 0 {
+        return Err(parse::Error::at(
+            input,
+            &digits[digits.len() - incomplete..],
+            format!("incomplete layer of {} out of {} digits", incomplete, size),
+        )
+        .into());
+    }
+    Ok(digits.as_bytes().chunks(size).collect())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    layers: Vec<Vec<Pixel>>,
+}
+
+impl Image {
+    pub fn decode(input: &str, width: usize, height: usize) -> Result<Self> {
+        if let Some(idx) = input.find(|x: char| x.is_ascii_digit() && x > '2') {
+            return Err(
+                parse::Error::at(input, &input[idx..], "expected a pixel (0, 1 or 2)").into(),
+            );
+        }
+        let layers = layers(input, width, height)?
+            .into_iter()
+            .map(|layer| {
+                layer
+                    .iter()
+                    .map(|digit| match digit {
+                        b'0' => Pixel::Black,
+                        b'1' => Pixel::White,
+                        _ => Pixel::Transparent,
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(Self {
+            width,
+            height,
+            layers,
+        })
+    }
+
+    pub fn encode(&self) -> String {
+        self.layers.iter().flatten().map(|x| x.digit()).collect()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn layers(&self) -> &[Vec<Pixel>] {
+        &self.layers
+    }
+
+    /// The visible pixels: the first non-transparent one of every position.
+    pub fn composite(&self) -> Vec<Pixel> {
+        Self::stack(&self.layers, self.width * self.height)
+    }
+
+    /// The composite after stacking each layer, starting with the back one.
+    pub fn frames(&self) -> Vec<Vec<Pixel>> {
+        (0..self.layers.len())
+            .rev()
+            .map(|front| Self::stack(&self.layers[front..], self.width * self.height))
+            .collect()
+    }
+
+    fn stack(layers: &[Vec<Pixel>], size: usize) -> Vec<Pixel> {
+        (0..size)
+            .map(|idx| {
+                layers
+                    .iter()
+                    .map(|layer| layer[idx])
+                    .find(|&pixel| pixel != Pixel::Transparent)
+                    .unwrap_or(Pixel::Transparent)
+            })
+            .collect()
+    }
+
+    /// Rows of the composite, `true` for white pixels.
+    pub fn lit(&self) -> Vec<Vec<bool>> {
+        self.composite()
+            .chunks(self.width)
+            .map(|row| row.iter().map(|&pixel| pixel == Pixel::White).collect())
+            .collect()
+    }
+
+    /// The width and height after scaling up by `scale`.
+    fn size(&self, scale: usize) -> io::Result<(usize, usize)> {
+        let invalid = |message| io::Error::new(io::ErrorKind::InvalidInput, message);
+        if scale == 0 {
+            return Err(invalid("the scale must be at least 1"));
+        }
+        self.width
+            .checked_mul(scale)
+            .zip(self.height.checked_mul(scale))
+            .ok_or_else(|| invalid("scaled image too large"))
+    }
+
+    /// Scales `pixels` up by `scale` and converts them to RGBA.
+    fn rgba(&self, pixels: &[Pixel], scale: usize) -> Vec<u8> {
+        pixels
+            .chunks(self.width)
+            .flat_map(|row| {
+                let row = row
+                    .iter()
+                    .flat_map(|pixel| std::iter::repeat_n(pixel.rgba(), scale))
+                    .collect::<Vec<_>>();
+                std::iter::repeat_n(row, scale).flatten()
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// The composite as a binary PPM, transparent pixels drawn grey.
+    pub fn ppm(&self, scale: usize) -> io::Result<Vec<u8>> {
+        let (width, height) = self.size(scale)?;
+        let mut output = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        output.extend(
+            self.rgba(&self.composite(), scale)
+                .chunks(4)
+                .flat_map(|rgba| &rgba[..3]),
+        );
+        Ok(output)
+    }
+
+    /// The composite as a PNG with an alpha channel for transparent pixels.
+    pub fn png(&self, scale: usize) -> Result<Vec<u8>, png::EncodingError> {
+        let (width, height) = self.size(scale)?;
+        let size = |x: usize| {
+            u32::try_from(x).map_err(|_| {
+                io::Error::new(io::ErrorKind::InvalidInput, "image too large for a PNG")
+            })
+        };
+        let (width, height) = (size(width)?, size(height)?);
+        let mut output = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut output, width, height);
+            encoder.set_color(png::ColorType::Rgba);
+            encoder.set_depth(png::BitDepth::Eight);
+            let mut writer = encoder.write_header()?;
+            writer.write_image_data(&self.rgba(&self.composite(), scale))?;
+        }
+        Ok(output)
+    }
+
+    /// The composite as SVG, with a square per black or white pixel.
+    pub fn svg(&self, scale: usize) -> io::Result<String> {
+        let (width, height) = self.size(scale)?;
+        let mut output = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" shape-rendering=\"crispEdges\">\n",
+            width, height
+        );
+        for (idx, pixel) in self.composite().into_iter().enumerate() {
+            let fill = match pixel {
+                Pixel::Black => "black",
+                Pixel::White => "white",
+                Pixel::Transparent => continue,
+            };
+            let _ = writeln!(
+                output,
+                "  <rect x=\"{}\" y=\"{}\" width=\"{3}\" height=\"{3}\" fill=\"{}\"/>",
+                idx % self.width * scale,
+                idx / self.width * scale,
+                fill,
+                scale
+            );
+        }
+        output.push_str("</svg>\n");
+        Ok(output)
+    }
+
+    /// An animated GIF of the `frames`, showing `delay` hundredths of a second each.
+    pub fn gif(&self, scale: usize, delay: u16) -> Result<Vec<u8>, gif::EncodingError> {
+        let (width, height) = self.size(scale)?;
+        let size = |x: usize| {
+            u16::try_from(x).map_err(|_| {
+                io::Error::new(io::ErrorKind::InvalidInput, "image too large for a GIF")
+            })
+        };
+        let (width, height) = (size(width)?, size(height)?);
+        let mut output = Vec::new();
+        {
+            let mut encoder = gif::Encoder::new(&mut output, width, height, &[])?;
+            encoder.set_repeat(gif::Repeat::Infinite)?;
+            for frame in self.frames() {
+                let mut rgba = self.rgba(&frame, scale);
+                let mut frame = gif::Frame::from_rgba_speed(width, height, &mut rgba, 10);
+                frame.delay = delay;
+                encoder.write_frame(&frame)?;
+            }
+        }
+        Ok(output)
+    }
+
+    /// Writes every frame as a numbered PPM through `create`.
+    pub fn dump<W: Write>(
+        &self,
+        scale: usize,
+        mut create: impl FnMut(usize) -> io::Result<W>,
+    ) -> io::Result<()> {
+        for (idx, frame) in self.frames().into_iter().enumerate() {
+            let image = Self {
+                layers: vec![frame],
+                ..self.clone()
+            };
+            create(idx)?.write_all(&image.ppm(scale)?)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.composite().chunks(self.width) {
+            for pixel in row {
+                f.write_str(match pixel {
+                    Pixel::Black => "■",
+                    Pixel::White => "□",
+                    Pixel::Transparent => " ",
+                })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "0222112222120000";
+
+    #[test]
+    fn codec() {
+        let image = Image::decode(INPUT, 2, 2).unwrap();
+        assert_eq!(image.layers().len(), 4);
+        assert_eq!(image.encode(), INPUT);
+        assert_eq!(
+            image.composite(),
+            [Pixel::Black, Pixel::White, Pixel::White, Pixel::Black]
+        );
+        assert_eq!(
+            image.frames()[0],
+            [Pixel::Black, Pixel::Black, Pixel::Black, Pixel::Black]
+        );
+        assert_eq!(image.frames().last(), Some(&image.composite()));
+        assert_eq!(Image::decode("2222", 2, 2).unwrap().to_string(), "  \n  \n");
+    }
+
+    #[test]
+    fn exports() {
+        let image = Image::decode(INPUT, 2, 2).unwrap();
+        let ppm = image.ppm(2).unwrap();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+        assert!(image.png(1).unwrap().starts_with(b"\x89PNG"));
+        assert!(image.gif(1, 10).unwrap().starts_with(b"GIF89a"));
+        assert_eq!(image.svg(10).unwrap().matches("<rect").count(), 4);
+        assert!(image.ppm(0).is_err());
+        assert!(image.svg(0).is_err());
+        assert!(image.png(0).is_err());
+        assert!(image.gif(0, 10).is_err());
+        assert!(image.png(usize::MAX).is_err());
+        assert!(image.png(1 << 31).is_err());
+
+        let mut frames = Vec::new();
+        image
+            .dump(1, |idx| {
+                frames.push(idx);
+                Ok(io::sink())
+            })
+            .unwrap();
+        assert_eq!(frames, [0, 1, 2, 3]);
+    }
+}
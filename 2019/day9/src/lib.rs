@@ -0,0 +1,37 @@
+use aoc::{Error, Result, Solution};
+use aoc_2019_intcode::Intcode;
+use std::str::FromStr;
+
+fn run(input: &str, system_id: isize) -> Result<isize> {
+    Intcode::from_str(input)?
+        .run(vec![system_id])?
+        .wait()?
+        .output
+        .back()
+        .copied()
+        .ok_or_else(|| Error::invalid("program produced no output"))
+}
+
+fn part_one(input: &str) -> Result<isize> {
+    run(input, 1)
+}
+
+fn part_two(input: &str) -> Result<isize> {
+    run(input, 2)
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2019, 9).part(part_one).part(part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        assert!(part_one("3,0,4,0,x").is_err());
+        assert!(part_one("3,0,4,0,98").is_err());
+        assert!(part_two("99").is_err());
+    }
+}
@@ -1,18 +1,5 @@
-use aoc_2019_intcode::Intcode;
-use std::{error::Error, io::Read, str::FromStr};
+use std::process::ExitCode;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let mut input = String::new();
-    std::io::stdin().read_to_string(&mut input)?;
-
-    println!(
-        "part 1: {:?}",
-        Intcode::from_str(&input).unwrap().run(vec![1]).output
-    );
-    println!(
-        "part 2: {:?}",
-        Intcode::from_str(&input).unwrap().run(vec![2]).output
-    );
-
-    Ok(())
+fn main() -> ExitCode {
+    aoc_2019_day9::solution().main()
 }
@@ -1,4 +1,8 @@
-use std::{collections::VecDeque, ops::Index, str::FromStr};
+use aoc::{
+    parse::{self, integer, separated, tag},
+    Error, Result,
+};
+use std::{collections::VecDeque, convert::TryFrom, ops::Index, str::FromStr};
 
 #[derive(Copy, Clone, Debug)]
 enum Instruction {
@@ -58,13 +62,14 @@ enum ParameterMode {
     Relative,
 }
 
-impl From<isize> for ParameterMode {
-    fn from(input: isize) -> Self {
+impl TryFrom<isize> for ParameterMode {
+    type Error = Error;
+    fn try_from(input: isize) -> Result<Self> {
         match input {
-            0 => ParameterMode::Position,
-            1 => ParameterMode::Immediate,
-            2 => ParameterMode::Relative,
-            _ => unreachable!(),
+            0 => Ok(ParameterMode::Position),
+            1 => Ok(ParameterMode::Immediate),
+            2 => Ok(ParameterMode::Relative),
+            _ => Err(Error::invalid(format!("unknown parameter mode {}", input))),
         }
     }
 }
@@ -83,65 +88,106 @@ impl Index<usize> for Memory {
 }
 
 impl Memory {
-    fn init(program: Program) -> Self {
+    fn init(program: &[isize]) -> Self {
         let mut data = vec![0; program.len() * 8];
-        for (idx, op) in program.iter().enumerate() {
-            data[idx] = *op;
-        }
+        data[..program.len()].copy_from_slice(program);
         Memory {
             data,
             relative_base: 0,
         }
     }
 
-    fn adjust_base(&mut self, value: isize) {
-        self.relative_base += value;
+    fn adjust_base(&mut self, value: isize) -> Result<()> {
+        self.relative_base = self.relative(value)?;
+        Ok(())
     }
 
-    fn store(&mut self, address: Address, value: isize) {
-        self.data[address] = value;
+    fn relative(&self, value: isize) -> Result<isize> {
+        value.checked_add(self.relative_base).ok_or_else(|| {
+            Error::invalid(format!(
+                "relative base {} + {} overflows",
+                self.relative_base, value
+            ))
+        })
     }
 
-    fn param(&self, value: isize, mode: ParameterMode) -> isize {
-        match mode {
-            ParameterMode::Position => self.data[value as usize],
-            ParameterMode::Immediate => value,
-            ParameterMode::Relative => self.data[(value + self.relative_base) as usize],
-        }
+    fn store(&mut self, address: Address, value: isize) -> Result<()> {
+        let len = self.data.len();
+        *self.data.get_mut(address).ok_or_else(|| {
+            Error::invalid(format!("address {} out of bounds ({})", address, len))
+        })? = value;
+        Ok(())
     }
 
-    fn address(&self, value: isize, mode: ParameterMode) -> usize {
+    fn load(&self, address: isize) -> Result<isize> {
+        usize::try_from(address)
+            .ok()
+            .and_then(|address| self.data.get(address))
+            .copied()
+            .ok_or_else(|| {
+                Error::invalid(format!(
+                    "address {} out of bounds ({})",
+                    address,
+                    self.data.len()
+                ))
+            })
+    }
+
+    fn param(&self, value: isize, mode: ParameterMode) -> Result<isize> {
         match mode {
-            ParameterMode::Position => value as usize,
-            ParameterMode::Immediate => unreachable!(),
-            ParameterMode::Relative => (value + self.relative_base) as usize,
+            ParameterMode::Position => self.load(value),
+            ParameterMode::Immediate => Ok(value),
+            ParameterMode::Relative => self.load(self.relative(value)?),
         }
     }
 
-    fn decode(&self, address: Address) -> Instruction {
-        let opcode = &self.data[address];
-        let mem = &self.data[address + 1..];
-        let modes = vec![
-            (opcode / 100 % 10).into(),
-            (opcode / 1000 % 10).into(),
-            (opcode / 10000 % 10).into(),
+    fn address(&self, value: isize, mode: ParameterMode) -> Result<usize> {
+        let address = match mode {
+            ParameterMode::Position => value,
+            ParameterMode::Immediate => {
+                return Err(Error::invalid("immediate mode used for a write address"))
+            }
+            ParameterMode::Relative => self.relative(value)?,
+        };
+        usize::try_from(address)
+            .map_err(|_| Error::invalid(format!("negative address {}", address)))
+    }
+
+    fn decode(&self, address: Address) -> Result<Instruction> {
+        let opcode = self.load(address as isize)?;
+        let modes = [
+            ParameterMode::try_from(opcode / 100 % 10)?,
+            ParameterMode::try_from(opcode / 1000 % 10)?,
+            ParameterMode::try_from(opcode / 10000 % 10)?,
         ];
-        let param = |idx| self.param(mem[idx], modes[idx]);
-        let address = |idx| self.address(mem[idx], modes[idx]);
-
-        match opcode % 100 {
-            1 => Instruction::Add(param(0), param(1), address(2)),
-            2 => Instruction::Mul(param(0), param(1), address(2)),
-            3 => Instruction::Input(address(0)),
-            4 => Instruction::Output(param(0)),
-            5 => Instruction::JumpIfTrue(param(0), param(1) as usize),
-            6 => Instruction::JumpIfFalse(param(0), param(1) as usize),
-            7 => Instruction::LessThan(param(0), param(1), address(2)),
-            8 => Instruction::Equals(param(0), param(1), address(2)),
-            9 => Instruction::AdjustBase(param(0)),
+        let raw = |idx: usize| self.load((address + 1 + idx) as isize);
+        let param = |idx: usize| self.param(raw(idx)?, modes[idx]);
+        let dest = |idx: usize| self.address(raw(idx)?, modes[idx]);
+        let target = |idx: usize| {
+            param(idx).and_then(|target| {
+                usize::try_from(target)
+                    .map_err(|_| Error::invalid(format!("negative jump target {}", target)))
+            })
+        };
+
+        Ok(match opcode % 100 {
+            1 => Instruction::Add(param(0)?, param(1)?, dest(2)?),
+            2 => Instruction::Mul(param(0)?, param(1)?, dest(2)?),
+            3 => Instruction::Input(dest(0)?),
+            4 => Instruction::Output(param(0)?),
+            5 => Instruction::JumpIfTrue(param(0)?, target(1)?),
+            6 => Instruction::JumpIfFalse(param(0)?, target(1)?),
+            7 => Instruction::LessThan(param(0)?, param(1)?, dest(2)?),
+            8 => Instruction::Equals(param(0)?, param(1)?, dest(2)?),
+            9 => Instruction::AdjustBase(param(0)?),
             99 => Instruction::Halt,
-            _ => unreachable!(),
-        }
+            _ => {
+                return Err(Error::invalid(format!(
+                    "unknown opcode {} at {}",
+                    opcode, address
+                )))
+            }
+        })
     }
 }
 
@@ -155,22 +201,19 @@ pub struct Intcode {
 }
 
 impl FromStr for Intcode {
-    type Err = ();
+    type Err = parse::Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        Ok(Intcode::load(
-            input
-                .lines()
-                .next()
-                .unwrap()
-                .split(',')
-                .filter_map(|x| x.parse::<isize>().ok())
-                .collect(),
-        ))
+        parse::complete(separated(integer(), tag(",")), input).map(Intcode::load)
     }
 }
 
 impl Intcode {
     pub fn load(program: Program) -> Self {
+        Self::new(&program)
+    }
+
+    /// Boots a computer with a copy of `program`, so it can be shared between runs.
+    pub fn new(program: &[isize]) -> Self {
         Intcode {
             program_counter: 0,
             done: false,
@@ -180,33 +223,43 @@ impl Intcode {
         }
     }
 
-    pub fn run<T: IntoIterator<Item = isize>>(&mut self, input: T) -> &mut Self {
+    pub fn run<T: IntoIterator<Item = isize>>(&mut self, input: T) -> Result<&mut Self> {
         self.input.extend(input);
         loop {
-            let instruction = self.memory.decode(self.program_counter);
+            let instruction = self.memory.decode(self.program_counter)?;
             self.program_counter = instruction.jump(self.program_counter);
             match instruction {
                 Instruction::Add(a, b, dest) => {
-                    self.memory.store(dest, a + b);
+                    let sum = a
+                        .checked_add(b)
+                        .ok_or_else(|| Error::invalid(format!("{} + {} overflows", a, b)))?;
+                    self.memory.store(dest, sum)?;
                 }
                 Instruction::Mul(a, b, dest) => {
-                    self.memory.store(dest, a * b);
+                    let product = a
+                        .checked_mul(b)
+                        .ok_or_else(|| Error::invalid(format!("{} * {} overflows", a, b)))?;
+                    self.memory.store(dest, product)?;
                 }
                 Instruction::Input(dest) => {
-                    self.memory.store(dest, self.input.pop_front().unwrap());
+                    let value = self
+                        .input
+                        .pop_front()
+                        .ok_or_else(|| Error::invalid("program expects more input"))?;
+                    self.memory.store(dest, value)?;
                 }
                 Instruction::Output(value) => {
                     self.output.push_back(value);
                     break;
                 }
                 Instruction::LessThan(a, b, dest) => {
-                    self.memory.store(dest, (a < b).into());
+                    self.memory.store(dest, (a < b).into())?;
                 }
                 Instruction::Equals(a, b, dest) => {
-                    self.memory.store(dest, (a == b).into());
+                    self.memory.store(dest, (a == b).into())?;
                 }
                 Instruction::AdjustBase(value) => {
-                    self.memory.adjust_base(value);
+                    self.memory.adjust_base(value)?;
                 }
                 Instruction::Halt => {
                     self.done = true;
@@ -215,16 +268,12 @@ impl Intcode {
                 _ => {}
             }
         }
-        self
+        Ok(self)
     }
 
-    pub fn wait(&mut self) -> &mut Self {
-        let c = self.run(vec![]);
-        if c.done {
-            c
-        } else {
-            c.wait()
-        }
+    pub fn wait(&mut self) -> Result<&mut Self> {
+        while !self.run(vec![])?.done {}
+        Ok(self)
     }
 }
 
@@ -237,12 +286,12 @@ mod tests {
 
     fn test_program_with_input(program: Program, input: isize) -> VecDeque<isize> {
         let mut c = Intcode::load(program);
-        c.run(vec![input]);
+        c.run(vec![input]).unwrap();
         c.output
     }
 
     fn test_program(program: Program) -> isize {
-        Intcode::load(program).run(vec![0]).memory[0]
+        Intcode::load(program).run(vec![0]).unwrap().memory[0]
     }
 
     #[test]
@@ -302,17 +351,22 @@ mod tests {
                 109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99
             ])
             .wait()
+            .unwrap()
             .output,
             vec![109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99]
         );
         assert_eq!(
             Intcode::load(vec![1102, 34915192, 34915192, 7, 4, 7, 99, 0])
                 .wait()
+                .unwrap()
                 .output,
             vec![1219070632396864]
         );
         assert_eq!(
-            Intcode::load(vec![104, 1125899906842624, 99]).wait().output,
+            Intcode::load(vec![104, 1125899906842624, 99])
+                .wait()
+                .unwrap()
+                .output,
             vec![1125899906842624]
         );
     }
@@ -320,8 +374,37 @@ mod tests {
     #[test]
     fn instruction_decode() {
         let instruction = 1002;
-        assert_eq!(instruction % 100, 02);
+        assert_eq!(instruction % 100, 2);
         assert_eq!(instruction / 100 % 10, 0);
         assert_eq!(instruction / 1000 % 10, 1);
     }
+
+    #[test]
+    fn malformed() {
+        assert!(Intcode::from_str("1,0,x,0,99").is_err());
+        assert!(Intcode::load(vec![42]).run(vec![]).is_err());
+        assert!(Intcode::load(vec![3, 0, 99]).run(vec![]).is_err());
+        assert!(Intcode::load(vec![1, -1, 0, 0, 99]).run(vec![]).is_err());
+        assert!(Intcode::load(vec![1105, 1, -5]).run(vec![]).is_err());
+    }
+
+    #[test]
+    fn overflow() {
+        assert!(Intcode::from_str("1101,9223372036854775807,1,0,99")
+            .unwrap()
+            .run(vec![])
+            .is_err());
+        assert!(Intcode::from_str("1102,9223372036854775807,2,0,99")
+            .unwrap()
+            .run(vec![])
+            .is_err());
+        assert!(Intcode::from_str("109,9223372036854775807,109,1,99")
+            .unwrap()
+            .run(vec![])
+            .is_err());
+        assert!(Intcode::from_str("109,-2,204,-9223372036854775807,99")
+            .unwrap()
+            .run(vec![])
+            .is_err());
+    }
 }
@@ -0,0 +1,40 @@
+use aoc_2020_day1::ksum;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Entries from a fixed linear congruential generator, none of which sum to the
+/// target, so every search is exhaustive.
+fn entries(len: usize) -> Vec<usize> {
+    let mut state = 0x2020_u64;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (state >> 33) as usize % 1_000_000 * 2
+        })
+        .collect()
+}
+
+fn ksum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ksum");
+    group.sample_size(10);
+    for len in [200, 1_000] {
+        let entries = entries(len);
+        for k in [2, 3] {
+            group.bench_with_input(
+                BenchmarkId::new(format!("sorted k={}", k), len),
+                &entries,
+                |b, entries| b.iter(|| ksum::all(entries, k, 1_000_001)),
+            );
+            group.bench_with_input(
+                BenchmarkId::new(format!("naive k={}", k), len),
+                &entries,
+                |b, entries| b.iter(|| ksum::naive(entries, k, 1_000_001)),
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, ksum);
+criterion_main!(benches);
@@ -0,0 +1,191 @@
+//! Finds `k` entries at distinct indices that sum to a target, by sorting the
+//! entries and closing in on the last two with a pair of pointers.
+
+/// Entries summing to the target, ordered by index.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Combination {
+    pub indices: Vec<usize>,
+    pub values: Vec<usize>,
+}
+
+impl Combination {
+    fn new(entries: &[usize], mut indices: Vec<usize>) -> Self {
+        indices.sort_unstable();
+        Self {
+            values: indices.iter().map(|&idx| entries[idx]).collect(),
+            indices,
+        }
+    }
+
+    pub fn product(&self) -> usize {
+        self.values.iter().product()
+    }
+}
+
+/// Every combination of `k` entries summing to `target`, ordered by indices.
+pub fn all(entries: &[usize], k: usize, target: usize) -> Vec<Combination> {
+    let mut combinations = Vec::new();
+    search(entries, k, target, |indices| {
+        combinations.push(Combination::new(entries, indices.to_vec()));
+        true
+    });
+    combinations.sort_unstable();
+    combinations
+}
+
+/// Any combination of `k` entries summing to `target`.
+pub fn first(entries: &[usize], k: usize, target: usize) -> Option<Combination> {
+    let mut first = None;
+    search(entries, k, target, |indices| {
+        first = Some(Combination::new(entries, indices.to_vec()));
+        false
+    });
+    first
+}
+
+/// Every combination of `k` entries summing to `target`, trying all of them.
+pub fn naive(entries: &[usize], k: usize, target: usize) -> Vec<Combination> {
+    fn extend(
+        entries: &[usize],
+        start: usize,
+        k: usize,
+        target: usize,
+        indices: &mut Vec<usize>,
+        combinations: &mut Vec<Combination>,
+    ) {
+        if k == 0 {
+            if target == 0 {
+                combinations.push(Combination::new(entries, indices.clone()));
+            }
+            return;
+        }
+        for idx in start..entries.len() {
+            if let Some(rest) = target.checked_sub(entries[idx]) {
+                indices.push(idx);
+                extend(entries, idx + 1, k - 1, rest, indices, combinations);
+                indices.pop();
+            }
+        }
+    }
+
+    let mut combinations = Vec::new();
+    extend(entries, 0, k, target, &mut Vec::new(), &mut combinations);
+    combinations
+}
+
+/// Calls `visit` with the indices of each combination until it returns false.
+fn search(entries: &[usize], k: usize, target: usize, mut visit: impl FnMut(&[usize]) -> bool) {
+    let mut sorted = entries.iter().copied().zip(0..).collect::<Vec<_>>();
+    sorted.sort_unstable();
+    let mut indices = Vec::with_capacity(k);
+    fix(&sorted, k, target, &mut indices, &mut visit);
+}
+
+/// Fixes the smallest of the `k` entries and recurses on the larger ones, until
+/// two are left. Returns false once `visit` asks to stop.
+fn fix(
+    sorted: &[(usize, usize)],
+    k: usize,
+    target: usize,
+    indices: &mut Vec<usize>,
+    visit: &mut impl FnMut(&[usize]) -> bool,
+) -> bool {
+    match k {
+        0 => target != 0 || visit(indices),
+        1 => sorted
+            .iter()
+            .filter(|&&(value, _)| value == target)
+            .all(|&(_, idx)| {
+                indices.push(idx);
+                let next = visit(indices);
+                indices.pop();
+                next
+            }),
+        2 => pairs(sorted, target, indices, visit),
+        _ => {
+            for (offset, &(value, idx)) in sorted.iter().enumerate() {
+                // The remaining entries are at least as large.
+                if value.saturating_mul(k) > target {
+                    break;
+                }
+                indices.push(idx);
+                let next = fix(&sorted[offset + 1..], k - 1, target - value, indices, visit);
+                indices.pop();
+                if !next {
+                    return false;
+                }
+            }
+            true
+        }
+    }
+}
+
+/// Closes in on pairs summing to `target` from both ends of `sorted`.
+fn pairs(
+    sorted: &[(usize, usize)],
+    target: usize,
+    indices: &mut Vec<usize>,
+    visit: &mut impl FnMut(&[usize]) -> bool,
+) -> bool {
+    let mut emit = |a: usize, b: usize| {
+        indices.extend([sorted[a].1, sorted[b].1]);
+        let next = visit(indices);
+        indices.truncate(indices.len() - 2);
+        next
+    };
+    let (mut lo, mut hi) = (0, sorted.len());
+    while lo + 1 < hi {
+        let (low, high) = (sorted[lo].0, sorted[hi - 1].0);
+        match low.checked_add(high) {
+            Some(sum) if sum < target => lo += 1,
+            Some(sum) if sum == target && low == high => {
+                // Every pair of the equal entries in between.
+                return (lo..hi).all(|a| (a + 1..hi).all(|b| emit(a, b)));
+            }
+            Some(sum) if sum == target => {
+                let lows = lo + sorted[lo..].iter().take_while(|x| x.0 == low).count();
+                let highs = hi
+                    - sorted[..hi]
+                        .iter()
+                        .rev()
+                        .take_while(|x| x.0 == high)
+                        .count();
+                if !(lo..lows).all(|a| (highs..hi).all(|b| emit(a, b))) {
+                    return false;
+                }
+                lo = lows;
+                hi = highs;
+            }
+            _ => hi -= 1,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_naive() {
+        let entries = [5, 1, 3, 3, 2, 4, 0, 3, 6, 1, 5, 2];
+        for k in 0..=5 {
+            for target in 0..=20 {
+                assert_eq!(
+                    all(&entries, k, target),
+                    naive(&entries, k, target),
+                    "{} {}",
+                    k,
+                    target
+                );
+                assert_eq!(
+                    first(&entries, k, target).is_some(),
+                    !naive(&entries, k, target).is_empty()
+                );
+            }
+        }
+        assert_eq!(all(&[1010], 2, 2020), []);
+        assert_eq!(all(&[1010, 1010], 2, 2020).len(), 1);
+        assert_eq!(all(&[usize::MAX, 1], 2, 0), []);
+    }
+}
@@ -0,0 +1,54 @@
+use aoc::{
+    parse::{self, integer, lines},
+    Error, Result, Solution,
+};
+
+pub mod ksum;
+
+/// The product of `k` entries summing to 2020.
+fn product(entries: &[usize], k: usize) -> Result<usize> {
+    ksum::first(entries, k, 2020)
+        .map(|combination| combination.product())
+        .ok_or_else(|| Error::invalid(format!("no {} entries sum to 2020", k)))
+}
+
+fn parse(input: &str) -> Result<Vec<usize>> {
+    Ok(parse::complete(lines(integer()), input)?)
+}
+
+fn part_one(input: &str) -> Result<usize> {
+    product(&parse(input)?, 2)
+}
+
+fn part_two(input: &str) -> Result<usize> {
+    product(&parse(input)?, 3)
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2020, 1).part(part_one).part(part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: [usize; 6] = [1721, 979, 366, 299, 675, 1456];
+
+    #[test]
+    fn part_one() {
+        assert_eq!(product(&INPUT, 2), Ok(514579));
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(product(&INPUT, 3), Ok(241861950));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(super::part_one("1721\n97x").is_err());
+        assert!(super::part_one("3000\n1").is_err());
+        assert!(super::part_two("1721\n979").is_err());
+        assert!(super::part_two("1010\n5\n5").is_err());
+    }
+}
@@ -0,0 +1,216 @@
+use aoc::{
+    parse::{
+        self, any_char, integer, lines, map, space, tag, terminated, word, Input, PResult, Parser,
+    },
+    Result, Solution,
+};
+use std::{fmt, num::NonZeroUsize};
+
+#[derive(Debug, PartialEq)]
+pub struct Entry<'input> {
+    pub password: &'input str,
+    pub policy: Policy,
+}
+
+impl fmt::Display for Entry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}-{} {}: {}",
+            self.policy.start, self.policy.end, self.policy.character, self.password
+        )
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Policy {
+    pub start: usize,
+    pub end: usize,
+    pub character: char,
+}
+
+/// A way to read a policy.
+pub trait Dialect {
+    /// The reason `password` breaks `policy`, if it does.
+    fn check(&self, policy: &Policy, password: &str) -> Option<String>;
+}
+
+/// User-defined dialects.
+impl<F: Fn(&Policy, &str) -> Option<String>> Dialect for F {
+    fn check(&self, policy: &Policy, password: &str) -> Option<String> {
+        self(policy, password)
+    }
+}
+
+/// The character occurs at least `start` and at most `end` times.
+pub struct CountRange;
+
+impl Dialect for CountRange {
+    fn check(&self, policy: &Policy, password: &str) -> Option<String> {
+        let count = password
+            .chars()
+            .filter(|&char| policy.character == char)
+            .count();
+        (!(policy.start..=policy.end).contains(&count)).then(|| {
+            format!(
+                "{} occurs {} times, expected {}..={}",
+                policy.character, count, policy.start, policy.end
+            )
+        })
+    }
+}
+
+/// The character is at exactly one of the positions `start` and `end`,
+/// counting from 1.
+pub struct PositionalXor;
+
+impl Dialect for PositionalXor {
+    fn check(&self, policy: &Policy, password: &str) -> Option<String> {
+        let at = |position: usize| password.chars().nth(position - 1);
+        let (first, second) = (at(policy.start), at(policy.end));
+        let describe = |position: usize, char: Option<char>| match char {
+            Some(char) => format!("position {} ({})", position, char),
+            None => format!("position {} (past the end)", position),
+        };
+        match (
+            first == Some(policy.character),
+            second == Some(policy.character),
+        ) {
+            (true, true) => Some(format!(
+                "both positions {} and {} are {}",
+                policy.start, policy.end, policy.character
+            )),
+            (false, false) => Some(format!(
+                "neither {} nor {} is {}",
+                describe(policy.start, first),
+                describe(policy.end, second),
+                policy.character
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Every entry breaking the policy in `dialect`, with its line and the reason.
+pub fn audit(entries: &[Entry], dialect: &impl Dialect) -> String {
+    entries
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, entry)| {
+            let reason = dialect.check(&entry.policy, entry.password)?;
+            Some(format!("line {}: {}: {}\n", idx + 1, entry, reason))
+        })
+        .collect()
+}
+
+fn position<'a>() -> impl Parser<'a, usize> {
+    map(integer(), NonZeroUsize::get)
+}
+
+fn entry(input: Input) -> PResult<Entry> {
+    let (start, input) = terminated(position(), tag("-")).parse(input)?;
+    let (end, input) = terminated(position(), space()).parse(input)?;
+    let (character, input) = terminated(any_char(), tag(": ")).parse(input)?;
+    let (password, input) = word().parse(input)?;
+    Ok((
+        Entry {
+            password,
+            policy: Policy {
+                start,
+                end,
+                character,
+            },
+        },
+        input,
+    ))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Entry<'_>>> {
+    Ok(parse::complete(lines(entry), input)?)
+}
+
+fn valid(input: &str, dialect: impl Dialect) -> Result<usize> {
+    Ok(parse(input)?
+        .iter()
+        .filter(|entry| dialect.check(&entry.policy, entry.password).is_none())
+        .count())
+}
+
+fn part_one(input: &str) -> Result<usize> {
+    valid(input, CountRange)
+}
+
+fn part_two(input: &str) -> Result<usize> {
+    valid(input, PositionalXor)
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2020, 2).part(part_one).part(part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r"1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc";
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            super::parse(INPUT).unwrap()[0],
+            Entry {
+                password: "abcde",
+                policy: Policy {
+                    start: 1,
+                    end: 3,
+                    character: 'a'
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn one() {
+        assert_eq!(part_one(INPUT), Ok(2));
+    }
+
+    #[test]
+    fn two() {
+        assert_eq!(part_two(INPUT), Ok(1));
+    }
+
+    #[test]
+    fn audit() {
+        let entries = super::parse(INPUT).unwrap();
+        assert_eq!(
+            super::audit(&entries, &CountRange),
+            "line 2: 1-3 b: cdefg: b occurs 0 times, expected 1..=3\n"
+        );
+        assert_eq!(
+            super::audit(&entries, &PositionalXor),
+            "line 2: 1-3 b: cdefg: neither position 1 (c) nor position 3 (e) is b\n\
+             line 3: 2-9 c: ccccccccc: both positions 2 and 9 are c\n"
+        );
+        let short = |policy: &Policy, password: &str| {
+            (password.len() > policy.end).then(|| format!("longer than {}", policy.end))
+        };
+        assert_eq!(
+            super::audit(&entries, &short),
+            "line 1: 1-3 a: abcde: longer than 3\n\
+             line 2: 1-3 b: cdefg: longer than 3\n"
+        );
+        assert!(PositionalXor.check(&entries[0].policy, "a").is_none());
+        assert_eq!(
+            PositionalXor.check(&entries[1].policy, "x"),
+            Some("neither position 1 (x) nor position 3 (past the end) is b".to_string())
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_one("1-3 a abcde").is_err());
+        assert!(part_two("0-3 a: abcde").is_err());
+    }
+}
@@ -0,0 +1,236 @@
+use aoc::{
+    parse::{self, keyword, lines, Input, PResult, Parser},
+    Error, Result, Solution,
+};
+use std::{collections::HashSet, fmt};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Tile {
+    Open,
+    Tree,
+}
+
+impl Tile {
+    fn is_tree(&self) -> bool {
+        self == &Tile::Tree
+    }
+}
+
+fn row(input: Input) -> PResult<Vec<Tile>> {
+    let tile = keyword([("#", Tile::Tree), (".", Tile::Open)]);
+    let (first, mut input) = tile.parse(input)?;
+    let mut tiles = vec![first];
+    while !input.is_empty() {
+        let (next, rest) = tile.parse(input)?;
+        tiles.push(next);
+        input = rest;
+    }
+    Ok((tiles, input))
+}
+
+/// Moves `right` columns, or left if negative, for every `down` rows.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Slope {
+    pub right: isize,
+    pub down: usize,
+}
+
+impl Slope {
+    pub fn new(right: isize, down: usize) -> Self {
+        Self { right, down }
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "right {}, down {}", self.right, self.down)
+    }
+}
+
+pub struct Grid {
+    width: usize,
+    height: usize,
+    tiles: Vec<Tile>,
+}
+
+impl Grid {
+    /// The positions along `slope` from the top left until the bottom, with
+    /// columns counted on the repeated map.
+    fn path(&self, slope: Slope) -> Result<impl Iterator<Item = (isize, usize)>> {
+        if slope.down == 0 {
+            return Err(Error::invalid(format!(
+                "{} never reaches the bottom",
+                slope
+            )));
+        }
+        Ok((0..self.height)
+            .step_by(slope.down)
+            .zip((0..).map(move |step| step * slope.right))
+            .map(|(y, x)| (x, y)))
+    }
+
+    fn tile(&self, x: isize, y: usize) -> Tile {
+        self.tiles[x.rem_euclid(self.width as isize) as usize + y * self.width]
+    }
+
+    /// The trees on the path along `slope`, not counting the starting square.
+    pub fn traverse(&self, slope: Slope) -> Result<usize> {
+        Ok(self
+            .path(slope)?
+            .skip(1)
+            .filter(|&(x, y)| self.tile(x, y).is_tree())
+            .count())
+    }
+
+    /// The trees on every slope moving at most `bound` columns either way and
+    /// between 1 and `bound` rows.
+    pub fn search(&self, bound: usize) -> Vec<(Slope, usize)> {
+        let bound = bound as isize;
+        (1..=bound as usize)
+            .flat_map(|down| (-bound..=bound).map(move |right| Slope::new(right, down)))
+            .filter_map(|slope| Some((slope, self.traverse(slope).ok()?)))
+            .collect()
+    }
+
+    /// The first slope within `bound` with the fewest trees.
+    pub fn fewest(&self, bound: usize) -> Option<(Slope, usize)> {
+        self.search(bound)
+            .into_iter()
+            .min_by_key(|&(_, trees)| trees)
+    }
+
+    /// The first slope within `bound` with the most trees.
+    pub fn most(&self, bound: usize) -> Option<(Slope, usize)> {
+        self.search(bound)
+            .into_iter()
+            .rev()
+            .max_by_key(|&(_, trees)| trees)
+    }
+
+    /// The map repeated as far as the path along `slope` goes, with `O` for
+    /// open squares and `X` for trees on the path.
+    pub fn render(&self, slope: Slope) -> Result<String> {
+        let path = self.path(slope)?.collect::<HashSet<_>>();
+        let left = path.iter().map(|&(x, _)| x).min().unwrap_or(0);
+        let right = path.iter().map(|&(x, _)| x).max().unwrap_or(0);
+        // Whole copies of the map, so it lines up with the original.
+        let width = self.width as isize;
+        let left = left.div_euclid(width) * width;
+        let right = (right.div_euclid(width) + 1) * width;
+
+        let mut output = String::new();
+        for y in 0..self.height {
+            for x in left..right {
+                output.push(match (self.tile(x, y), path.contains(&(x, y))) {
+                    (Tile::Open, false) => '.',
+                    (Tile::Tree, false) => '#',
+                    (Tile::Open, true) => 'O',
+                    (Tile::Tree, true) => 'X',
+                });
+            }
+            output.push('\n');
+        }
+        Ok(output)
+    }
+}
+
+pub fn parse_grid(input: &str) -> Result<Grid> {
+    let rows = parse::complete(lines(row), input)?;
+    let width = rows
+        .first()
+        .map(Vec::len)
+        .ok_or_else(|| Error::invalid("the map has no rows"))?;
+    if let Some(idx) = rows.iter().position(|row| row.len() != width) {
+        let line = input.lines().nth(idx).unwrap_or_default();
+        return Err(parse::Error::at(
+            input,
+            line,
+            format!("expected {} tiles, found {}", width, rows[idx].len()),
+        )
+        .into());
+    }
+    Ok(Grid {
+        width,
+        height: rows.len(),
+        tiles: rows.concat(),
+    })
+}
+
+fn part_one(input: &str) -> Result<usize> {
+    parse_grid(input)?.traverse(Slope::new(3, 1))
+}
+
+fn part_two(input: &str) -> Result<usize> {
+    let grid = parse_grid(input)?;
+    [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+        .iter()
+        .map(|&(right, down)| grid.traverse(Slope::new(right, down)))
+        .product()
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2020, 3).part(part_one).part(part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r"..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+";
+
+    #[test]
+    fn one() {
+        assert_eq!(part_one(INPUT), Ok(7));
+    }
+
+    #[test]
+    fn two() {
+        assert_eq!(part_two(INPUT), Ok(336));
+    }
+
+    #[test]
+    fn slopes() {
+        let grid = parse_grid(INPUT).unwrap();
+        assert_eq!(grid.traverse(Slope::new(-3, 1)), Ok(3));
+        assert_eq!(grid.traverse(Slope::new(0, 11)), Ok(0));
+        assert!(grid.traverse(Slope::new(1, 0)).is_err());
+        assert_eq!(grid.search(2).len(), 10);
+        assert_eq!(grid.fewest(3), Some((Slope::new(1, 3), 0)));
+        assert_eq!(grid.most(3).map(|(_, trees)| trees), Some(7));
+    }
+
+    #[test]
+    fn render() {
+        let grid = parse_grid("..#\n#..\n.#.\n").unwrap();
+        assert_eq!(
+            grid.render(Slope::new(2, 1)),
+            Ok("O.#..#\n#.O#..\n.#..X.\n".to_string())
+        );
+        assert_eq!(
+            grid.render(Slope::new(-1, 2)),
+            Ok("..#O.#\n#..#..\n.#O.#.\n".to_string())
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_one("..#\n.x.\n").is_err());
+        assert_eq!(
+            parse_grid("..#\n.#\n").err().map(|err| err.to_string()),
+            Some("2:1: expected 3 tiles, found 2\n  |\n2 | .#\n  | ^".to_string())
+        );
+        assert!(parse_grid("").is_err());
+        assert!(part_one("\n").is_err());
+    }
+}
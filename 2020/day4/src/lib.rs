@@ -0,0 +1,344 @@
+use aoc::{
+    parse::{self, blocks, map, record, take_while1, word},
+    Result, Solution,
+};
+use regex::Regex;
+use std::{collections::HashMap, fmt, iter::FromIterator, ops::RangeInclusive};
+
+/// How the value of a field is checked.
+pub enum Rule {
+    Any,
+    /// A number of exactly `digits` digits within the range.
+    Range {
+        digits: usize,
+        range: RangeInclusive<u32>,
+    },
+    /// A number followed by one of the units, with a range per unit.
+    Measure(Vec<(&'static str, RangeInclusive<u32>)>),
+    /// A value matching the regular expression.
+    Pattern(Regex),
+    /// One of the values.
+    OneOf(Vec<&'static str>),
+}
+
+impl Rule {
+    /// A year written with exactly four digits, within the range.
+    pub fn year(range: RangeInclusive<u32>) -> Self {
+        Rule::Range { digits: 4, range }
+    }
+
+    /// The reason `value` breaks the rule, if it does.
+    fn check(&self, value: &str) -> Option<String> {
+        // `u32::parse` also accepts a leading `+`.
+        let is_number =
+            |value: &str| !value.is_empty() && value.bytes().all(|x| x.is_ascii_digit());
+        let number = |value: &str, range: &RangeInclusive<u32>| match value.parse::<u32>() {
+            _ if !is_number(value) => Some("is not a number".to_string()),
+            Ok(number) if range.contains(&number) => None,
+            Ok(_) => Some(format!("out of {:?}", range)),
+            Err(_) => Some("is not a number".to_string()),
+        };
+        let reason = match self {
+            Rule::Any => None,
+            Rule::Range { digits, range } if value.len() != *digits || !is_number(value) => {
+                Some(format!("is not a {} digit number", digits))
+            }
+            Rule::Range { range, .. } => number(value, range),
+            Rule::Measure(units) => {
+                let split = value
+                    .find(|x: char| !x.is_ascii_digit())
+                    .unwrap_or(value.len());
+                let (amount, unit) = value.split_at(split);
+                match units.iter().find(|(name, _)| *name == unit) {
+                    Some((_, range)) => number(amount, range),
+                    None => Some(format!(
+                        "has no unit out of {}",
+                        units
+                            .iter()
+                            .map(|(name, _)| *name)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )),
+                }
+            }
+            Rule::Pattern(regex) => {
+                (!regex.is_match(value)).then(|| format!("doesn't match {}", regex))
+            }
+            Rule::OneOf(values) => {
+                (!values.contains(&value)).then(|| format!("is not one of {}", values.join(", ")))
+            }
+        };
+        reason.map(|reason| format!("{} {}", value, reason))
+    }
+}
+
+pub struct Field {
+    pub name: &'static str,
+    pub required: bool,
+    pub rule: Rule,
+}
+
+/// A field that is missing or has an invalid value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    pub field: &'static str,
+    pub reason: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.reason)
+    }
+}
+
+/// The fields a passport may have, with the rules for their values. Fields not
+/// in the schema are ignored.
+#[derive(Default)]
+pub struct Schema {
+    fields: Vec<Field>,
+}
+
+impl Schema {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field(mut self, name: &'static str, required: bool, rule: Rule) -> Self {
+        self.fields.push(Field {
+            name,
+            required,
+            rule,
+        });
+        self
+    }
+
+    /// Every field except `cid` is required, with any value.
+    pub fn presence() -> Self {
+        ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"]
+            .iter()
+            .fold(Self::new(), |schema, name| {
+                schema.field(name, true, Rule::Any)
+            })
+            .field("cid", false, Rule::Any)
+    }
+
+    /// The required fields must also have valid values.
+    pub fn strict() -> Self {
+        Self::new()
+            .field("byr", true, Rule::year(1920..=2002))
+            .field("iyr", true, Rule::year(2010..=2020))
+            .field("eyr", true, Rule::year(2020..=2030))
+            .field(
+                "hgt",
+                true,
+                Rule::Measure(vec![("cm", 150..=193), ("in", 59..=76)]),
+            )
+            .field(
+                "hcl",
+                true,
+                Rule::Pattern(Regex::new("^#[0-9a-f]{6}$").expect("valid regex")),
+            )
+            .field(
+                "ecl",
+                true,
+                Rule::OneOf(vec!["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]),
+            )
+            .field(
+                "pid",
+                true,
+                Rule::Pattern(Regex::new("^[0-9]{9}$").expect("valid regex")),
+            )
+            .field("cid", false, Rule::Any)
+    }
+
+    /// Every violation of the schema by `passport`, in the order of the fields.
+    pub fn validate(&self, passport: &HashMap<&str, &str>) -> Vec<Violation> {
+        self.fields
+            .iter()
+            .filter_map(|field| {
+                let reason = match passport.get(field.name) {
+                    Some(value) => field.rule.check(value)?,
+                    None if field.required => "missing".to_string(),
+                    None => return None,
+                };
+                Some(Violation {
+                    field: field.name,
+                    reason,
+                })
+            })
+            .collect()
+    }
+
+    /// The violations of every invalid passport, one passport per line.
+    pub fn report(&self, passports: &[HashMap<&str, &str>]) -> String {
+        passports
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, passport)| {
+                let violations = self.validate(passport);
+                (!violations.is_empty()).then(|| {
+                    let violations = violations
+                        .iter()
+                        .map(Violation::to_string)
+                        .collect::<Vec<_>>();
+                    format!("passport {}: {}\n", idx + 1, violations.join(", "))
+                })
+            })
+            .collect()
+    }
+}
+
+pub fn passports(input: &str) -> Result<Vec<HashMap<&str, &str>>> {
+    let field = take_while1("a field name", char::is_alphanumeric);
+    Ok(parse::complete(
+        blocks(map(record(field, ":", word()), HashMap::from_iter)),
+        input,
+    )?)
+}
+
+fn valid(input: &str, schema: Schema) -> Result<usize> {
+    Ok(passports(input)?
+        .iter()
+        .filter(|passport| schema.validate(passport).is_empty())
+        .count())
+}
+
+fn part_one(input: &str) -> Result<usize> {
+    valid(input, Schema::presence())
+}
+
+fn part_two(input: &str) -> Result<usize> {
+    valid(input, Schema::strict())
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2020, 4).part(part_one).part(part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT_1: &str = r"ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in
+";
+
+    static INPUT_2: &str = r"eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007
+";
+
+    static INPUT_3: &str = r"pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719";
+
+    #[test]
+    fn one() {
+        assert_eq!(part_one(INPUT_1), Ok(2));
+    }
+
+    #[test]
+    fn two() {
+        let check = |name: &str, value: &str| {
+            let schema = Schema::strict();
+            let field = schema.fields.iter().find(|x| x.name == name).unwrap();
+            field.rule.check(value)
+        };
+        assert_eq!(check("byr", "2002"), None);
+        assert_eq!(
+            check("byr", "2003"),
+            Some("2003 out of 1920..=2002".to_string())
+        );
+        assert_eq!(
+            check("byr", "+2000"),
+            Some("+2000 is not a 4 digit number".to_string())
+        );
+        assert!(check("byr", "02002").is_some());
+        assert!(check("byr", "200").is_some());
+        assert!(check("hgt", "+60in").is_some());
+
+        assert_eq!(check("hgt", "60in"), None);
+        assert_eq!(check("hgt", "190cm"), None);
+        assert_eq!(
+            check("hgt", "190in"),
+            Some("190in out of 59..=76".to_string())
+        );
+        assert_eq!(
+            check("hgt", "190"),
+            Some("190 has no unit out of cm, in".to_string())
+        );
+
+        assert_eq!(check("hcl", "#123abc"), None);
+        assert!(check("hcl", "#123abz").is_some());
+        assert_eq!(
+            check("hcl", "123abc"),
+            Some("123abc doesn't match ^#[0-9a-f]{6}$".to_string())
+        );
+
+        assert_eq!(check("ecl", "brn"), None);
+        assert!(check("ecl", "wat").is_some());
+
+        assert_eq!(check("pid", "000000001"), None);
+        assert!(check("pid", "0123456789").is_some());
+
+        assert_eq!(part_two(INPUT_2), Ok(0));
+        assert_eq!(part_two(INPUT_3), Ok(4));
+    }
+
+    #[test]
+    fn report() {
+        let invalid = passports(INPUT_2).unwrap();
+        assert_eq!(
+            Schema::strict()
+                .validate(&invalid[0])
+                .iter()
+                .map(Violation::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "eyr: 1972 out of 2020..=2030",
+                "hgt: 170 has no unit out of cm, in",
+                "pid: 186cm doesn't match ^[0-9]{9}$"
+            ]
+        );
+        assert_eq!(
+            Schema::presence().report(&passports(INPUT_1).unwrap()),
+            "passport 2: hgt: missing\npassport 4: byr: missing\n"
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_one("ecl:gry pid").is_err());
+        assert!(part_two("ecl:gry\n:860033327").is_err());
+    }
+}
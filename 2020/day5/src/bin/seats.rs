@@ -0,0 +1,35 @@
+use day5::SeatMap;
+use std::{env, fs, process::ExitCode};
+
+const USAGE: &str = "usage: seats <input>";
+
+/// Prefixes `err` with `path`, joined like `path:line:column` for parse errors.
+fn located(path: &str, err: aoc::Error) -> String {
+    match err {
+        aoc::Error::Parse(_) => format!("{}:{}", path, err),
+        aoc::Error::Invalid(_) => format!("{}: {}", path, err),
+    }
+}
+
+fn main() -> ExitCode {
+    let path = match env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    match fs::read_to_string(&path)
+        .map_err(|err| format!("{}: {}", path, err))
+        .and_then(|input| day5::parse(&input).map_err(|err| located(&path, err)))
+    {
+        Ok((codec, seats)) => {
+            print!("{}", SeatMap::new(codec, &seats));
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
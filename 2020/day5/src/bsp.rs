@@ -0,0 +1,185 @@
+//! Boarding passes as binary space partitioning. Each character keeps the lower
+//! or upper half of the remaining rows or columns, so a pass is just the seat's
+//! row and column written in binary with a custom alphabet.
+
+use aoc::{
+    parse::{self, any_char, Input, Parser},
+    Error, Result,
+};
+
+/// The characters selecting the lower and upper half, and how many times the
+/// seats are halved along this axis.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Axis {
+    pub lower: char,
+    pub upper: char,
+    pub bits: u32,
+}
+
+impl Axis {
+    pub fn new(lower: char, upper: char, bits: u32) -> Self {
+        Self { lower, upper, bits }
+    }
+
+    pub fn size(&self) -> usize {
+        1 << self.bits
+    }
+
+    fn parser<'a>(self) -> impl Parser<'a, usize> {
+        move |mut input: Input<'a>| {
+            let mut value = 0;
+            for _ in 0..self.bits {
+                let bit = match any_char().parse(input) {
+                    Ok((char, rest)) if char == self.lower => (0, rest),
+                    Ok((char, rest)) if char == self.upper => (1, rest),
+                    _ => return input.fail(format!("{} or {}", self.lower, self.upper)),
+                };
+                value = value << 1 | bit.0;
+                input = bit.1;
+            }
+            Ok((value, input))
+        }
+    }
+
+    fn encode(&self, value: usize, output: &mut String) {
+        output.extend((0..self.bits).rev().map(|bit| match value >> bit & 1 {
+            0 => self.lower,
+            _ => self.upper,
+        }));
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Seat {
+    pub row: usize,
+    pub column: usize,
+}
+
+/// The most row and column bits together, keeping seat maps to a million seats.
+pub const MAX_BITS: u32 = 20;
+
+/// Decodes boarding passes with the row characters followed by the column
+/// characters, and encodes seats back into passes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Codec {
+    row: Axis,
+    column: Axis,
+}
+
+impl Default for Codec {
+    /// The airline's plane with 128 rows of 8 seats.
+    fn default() -> Self {
+        Self {
+            row: Axis::new('F', 'B', 7),
+            column: Axis::new('L', 'R', 3),
+        }
+    }
+}
+
+impl Codec {
+    pub fn new(row: Axis, column: Axis) -> Result<Self> {
+        if let Some(axis) = [row, column].iter().find(|axis| axis.lower == axis.upper) {
+            return Err(Error::invalid(format!(
+                "both halves are selected by {}",
+                axis.lower
+            )));
+        }
+        if row.bits + column.bits > MAX_BITS {
+            return Err(Error::invalid(format!(
+                "passes of {} characters are too long, at most {} fit a plane",
+                row.bits + column.bits,
+                MAX_BITS
+            )));
+        }
+        Ok(Self { row, column })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.row.size()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.column.size()
+    }
+
+    /// The number of seats on the plane, one more than the highest id.
+    pub fn seats(&self) -> usize {
+        self.rows() * self.columns()
+    }
+
+    /// The row followed by the column, read as one binary number.
+    pub fn id(&self, seat: Seat) -> usize {
+        seat.row << self.column.bits | seat.column
+    }
+
+    pub fn seat(&self, id: usize) -> Option<Seat> {
+        (id < self.seats()).then(|| Seat {
+            row: id >> self.column.bits,
+            column: id & (self.columns() - 1),
+        })
+    }
+
+    pub fn parser<'a>(&self) -> impl Parser<'a, Seat> {
+        let (row, column) = (self.row.parser(), self.column.parser());
+        move |input| {
+            let (row, input) = row.parse(input)?;
+            let (column, input) = column.parse(input)?;
+            Ok((Seat { row, column }, input))
+        }
+    }
+
+    pub fn decode(&self, pass: &str) -> Result<Seat> {
+        Ok(parse::complete(self.parser(), pass)?)
+    }
+
+    pub fn encode(&self, seat: Seat) -> Result<String> {
+        if seat.row >= self.rows() || seat.column >= self.columns() {
+            return Err(Error::invalid(format!(
+                "row {} column {} is not on a plane with {} rows of {} seats",
+                seat.row,
+                seat.column,
+                self.rows(),
+                self.columns()
+            )));
+        }
+        let mut pass = String::new();
+        self.row.encode(seat.row, &mut pass);
+        self.column.encode(seat.column, &mut pass);
+        Ok(pass)
+    }
+
+    pub fn encode_id(&self, id: usize) -> Result<String> {
+        let seat = self
+            .seat(id)
+            .ok_or_else(|| Error::invalid(format!("seat {} is not below {}", id, self.seats())))?;
+        self.encode(seat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let codec = Codec::default();
+        let seat = Seat { row: 44, column: 5 };
+        assert_eq!(codec.decode("FBFBBFFRLR"), Ok(seat));
+        assert_eq!(codec.id(seat), 357);
+        assert_eq!(codec.encode_id(357).as_deref(), Ok("FBFBBFFRLR"));
+        assert!(codec.encode_id(1024).is_err());
+        assert!(codec.encode(Seat { row: 0, column: 8 }).is_err());
+
+        let codec = Codec::new(Axis::new('0', '1', 2), Axis::new('a', 'b', 1)).unwrap();
+        for id in 0..codec.seats() {
+            let pass = codec.encode_id(id).unwrap();
+            assert_eq!(codec.decode(&pass).map(|seat| codec.id(seat)), Ok(id));
+        }
+        assert_eq!(codec.encode_id(5).as_deref(), Ok("10b"));
+        assert!(codec.decode("10c").is_err());
+        assert!(Codec::new(Axis::new('F', 'F', 7), Axis::new('L', 'R', 3)).is_err());
+        assert!(Codec::new(Axis::new('F', 'B', 60), Axis::new('L', 'R', 4)).is_err());
+        assert!(Codec::new(Axis::new('F', 'B', 17), Axis::new('L', 'R', 3)).is_ok());
+        assert!(Codec::new(Axis::new('F', 'B', 18), Axis::new('L', 'R', 3)).is_err());
+    }
+}
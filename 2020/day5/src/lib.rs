@@ -0,0 +1,141 @@
+pub mod bsp;
+
+use aoc::{
+    parse::{self, lines},
+    Error, Result, Solution,
+};
+use bsp::{Axis, Codec, Seat};
+use std::fmt;
+
+/// The plane the passes in `input` were issued for, taking the leading `F`s
+/// and `B`s as the row and the rest of the first pass as the column.
+pub fn plane(input: &str) -> Result<Codec> {
+    let pass = input.lines().next().unwrap_or_default();
+    let rows = pass.chars().take_while(|&x| x == 'F' || x == 'B').count();
+    let columns = pass.chars().count() - rows;
+    Codec::new(
+        Axis::new('F', 'B', rows as u32),
+        Axis::new('L', 'R', columns as u32),
+    )
+}
+
+pub fn parse(input: &str) -> Result<(Codec, Vec<Seat>)> {
+    let codec = plane(input)?;
+    Ok((codec, parse::complete(lines(codec.parser()), input)?))
+}
+
+/// Which seats on a plane are taken.
+pub struct SeatMap {
+    codec: Codec,
+    taken: Vec<bool>,
+}
+
+impl SeatMap {
+    pub fn new(codec: Codec, seats: &[Seat]) -> Self {
+        let mut taken = vec![false; codec.seats()];
+        for &seat in seats {
+            taken[codec.id(seat)] = true;
+        }
+        Self { codec, taken }
+    }
+
+    /// The first empty seat with both neighbouring ids taken.
+    pub fn missing(&self) -> Option<usize> {
+        self.taken
+            .windows(3)
+            .position(|window| window == [true, false, true])
+            .map(|id| id + 1)
+    }
+}
+
+impl fmt::Display for SeatMap {
+    /// A row per line with `#` for taken seats, `.` for empty ones and `X` for
+    /// the missing seat.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let missing = self.missing();
+        let width = (self.codec.rows() - 1).to_string().len();
+        for (row, seats) in self.taken.chunks(self.codec.columns()).enumerate() {
+            write!(f, "{:>width$} ", row, width = width)?;
+            for (column, &taken) in seats.iter().enumerate() {
+                let id = row * self.codec.columns() + column;
+                let char = match taken {
+                    true => '#',
+                    false if Some(id) == missing => 'X',
+                    false => '.',
+                };
+                write!(f, "{}", char)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn part_one(input: &str) -> Result<usize> {
+    let (codec, seats) = parse(input)?;
+    Ok(seats
+        .into_iter()
+        .map(|seat| codec.id(seat))
+        .max()
+        .unwrap_or(0))
+}
+
+fn part_two(input: &str) -> Result<usize> {
+    let (codec, seats) = parse(input)?;
+    SeatMap::new(codec, &seats)
+        .missing()
+        .ok_or_else(|| Error::invalid("no empty seat between two taken seats"))
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2020, 5).part(part_one).part(part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_one(input: &str) -> Result<Seat> {
+        parse(input).map(|(_, seats)| seats[0])
+    }
+
+    #[test]
+    fn one() {
+        assert_eq!(parse_one("FBFBBFFRLR"), Ok(Seat { row: 44, column: 5 }));
+        assert_eq!(parse_one("BFFFBBFRRR"), Ok(Seat { row: 70, column: 7 }));
+        assert_eq!(parse_one("FFFBBBFRRR"), Ok(Seat { row: 14, column: 7 }));
+        assert_eq!(
+            parse_one("BBFFBBFRLL"),
+            Ok(Seat {
+                row: 102,
+                column: 4
+            })
+        );
+    }
+
+    #[test]
+    fn two() {
+        assert_eq!(part_two("FFFFFFFLLL\nFFFFFFFLRL"), Ok(1));
+        assert!(part_two("FFFFFFFLLL").is_err());
+        assert_eq!(part_two("FFR\nFBR\nBFL\nBFR\nBBL\nBBR"), Ok(2));
+    }
+
+    #[test]
+    fn seat_map() {
+        let (codec, seats) = parse("FFR\nFBR\nBFL\nBFR\nBBL\nBBR").unwrap();
+        assert_eq!(codec.seats(), 8);
+        assert_eq!(
+            SeatMap::new(codec, &seats).to_string(),
+            "0 .#\n1 X#\n2 ##\n3 ##\n"
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_one("FBFBBFFRLR\nFBFBBFFRL").is_err());
+        assert!(part_one("FBFBBFFRLR\nFBFBBFFRLRR").is_err());
+        assert!(part_one("FBFBBFFRLR\nFBFBBFFFLR").is_err());
+        assert!(part_two(&"F".repeat(63)).is_err());
+        assert!(part_one("FBFBXFFRLR").is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use aoc::{
+    parse::{self, blocks, lines, map_res, take_while1},
+    Error, Result, Solution,
+};
+use std::{fmt, iter::FromIterator, str::FromStr};
+
+/// The questions a to z someone answered yes to, one bit each.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AnswerSet(u32);
+
+impl AnswerSet {
+    pub const EMPTY: Self = Self(0);
+    pub const ALL: Self = Self((1 << 26) - 1);
+
+    /// The bit for `question`, if it is one of a to z.
+    fn bit(question: char) -> Option<u32> {
+        question
+            .is_ascii_lowercase()
+            .then(|| 1 << (question as u8 - b'a'))
+    }
+
+    pub fn contains(self, question: char) -> bool {
+        Self::bit(question).is_some_and(|bit| self.0 & bit != 0)
+    }
+
+    pub fn insert(&mut self, question: char) -> Result<()> {
+        let bit = Self::bit(question)
+            .ok_or_else(|| Error::invalid(format!("{:?} is not a question (a-z)", question)))?;
+        self.0 |= bit;
+        Ok(())
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = char> {
+        ('a'..='z').filter(move |&question| self.contains(question))
+    }
+}
+
+impl FromStr for AnswerSet {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut set = Self::EMPTY;
+        for question in s.chars() {
+            set.insert(question)?;
+        }
+        Ok(set)
+    }
+}
+
+impl fmt::Display for AnswerSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.iter()
+            .try_for_each(|question| write!(f, "{}", question))
+    }
+}
+
+/// The answers of each person in a group.
+pub type Group = Vec<AnswerSet>;
+
+/// The questions anyone in `group` answered.
+pub fn anyone(group: &[AnswerSet]) -> AnswerSet {
+    group.iter().fold(AnswerSet::EMPTY, |acc, &x| acc.union(x))
+}
+
+/// The questions everyone in `group` answered, none for an empty group.
+pub fn everyone(group: &[AnswerSet]) -> AnswerSet {
+    match group {
+        [] => AnswerSet::EMPTY,
+        _ => group
+            .iter()
+            .fold(AnswerSet::ALL, |acc, &x| acc.intersection(x)),
+    }
+}
+
+/// How many people in a group answered each question.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Tally {
+    people: usize,
+    counts: [usize; 26],
+}
+
+impl Tally {
+    pub fn add(&mut self, answers: AnswerSet) {
+        self.people += 1;
+        for question in answers.iter() {
+            self.counts[(question as u8 - b'a') as usize] += 1;
+        }
+    }
+
+    pub fn people(&self) -> usize {
+        self.people
+    }
+
+    pub fn count(&self, question: char) -> usize {
+        match question {
+            'a'..='z' => self.counts[(question as u8 - b'a') as usize],
+            _ => 0,
+        }
+    }
+
+    /// The questions answered by at least `k` people, and by at least one.
+    pub fn quorum(&self, k: usize) -> AnswerSet {
+        let bits = (0..26)
+            .filter(|&index| self.counts[index] >= k.max(1))
+            .fold(0, |bits, index| bits | 1 << index);
+        AnswerSet(bits)
+    }
+}
+
+impl FromIterator<AnswerSet> for Tally {
+    fn from_iter<I: IntoIterator<Item = AnswerSet>>(iter: I) -> Self {
+        let mut tally = Self::default();
+        iter.into_iter().for_each(|answers| tally.add(answers));
+        tally
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Group>> {
+    let answers = map_res(
+        take_while1("a question (a-z)", |x| x.is_ascii_lowercase()),
+        str::parse::<AnswerSet>,
+    );
+    Ok(parse::complete(blocks(lines(answers)), input)?)
+}
+
+fn part_one(input: &str) -> Result<usize> {
+    Ok(parse(input)?.iter().map(|group| anyone(group).len()).sum())
+}
+
+fn part_two(input: &str) -> Result<usize> {
+    Ok(parse(input)?
+        .iter()
+        .map(|group| everyone(group).len())
+        .sum())
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2020, 6).part(part_one).part(part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r"abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b";
+
+    #[test]
+    fn one() {
+        assert_eq!(part_one(INPUT), Ok(11));
+    }
+
+    #[test]
+    fn two() {
+        assert_eq!(part_two(INPUT), Ok(6));
+    }
+
+    #[test]
+    fn answer_sets() {
+        let abc = "abc".parse::<AnswerSet>().unwrap();
+        let bcd = "bdc".parse::<AnswerSet>().unwrap();
+        assert_eq!(abc.union(bcd).to_string(), "abcd");
+        assert_eq!(abc.intersection(bcd).to_string(), "bc");
+        assert_eq!(abc.difference(bcd).to_string(), "a");
+        assert_eq!(AnswerSet::ALL.len(), 26);
+        assert!(!abc.contains('A'));
+        let mut set = abc;
+        assert!(set.insert('A').is_err());
+        assert!(set.insert('{').is_err());
+        assert_eq!(set.insert('z'), Ok(()));
+        assert_eq!(set.to_string(), "abcz");
+        assert!("ab?".parse::<AnswerSet>().is_err());
+        assert_eq!(anyone(&[]), AnswerSet::EMPTY);
+        assert_eq!(everyone(&[]), AnswerSet::EMPTY);
+
+        let tally = [abc, bcd, "b".parse().unwrap()]
+            .iter()
+            .copied()
+            .collect::<Tally>();
+        assert_eq!(tally.count('b'), 3);
+        assert_eq!(tally.quorum(2).to_string(), "bc");
+        assert_eq!(tally.quorum(tally.people()).to_string(), "b");
+        assert_eq!(tally.quorum(0), abc.union(bcd));
+        assert_eq!(Tally::default().quorum(0), AnswerSet::EMPTY);
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_one("abc\n\na B").is_err());
+        assert!(part_two("ab\n1").is_err());
+    }
+}
@@ -0,0 +1,46 @@
+use day7::{query::Query, Rules};
+use std::{env, fs, process::ExitCode};
+
+const USAGE: &str = "usage: bags <input> <query>
+queries:
+  count inside \"<bag>\"
+  count containing \"<bag>\"
+  list containing \"<bag>\"
+  paths from \"<bag>\" to \"<bag>\"";
+
+/// Prefixes `err` with `path`, joined like `path:line:column` for parse errors.
+fn located(path: &str, err: aoc::Error) -> String {
+    match err {
+        aoc::Error::Parse(_) => format!("{}:{}", path, err),
+        aoc::Error::Invalid(_) => format!("{}: {}", path, err),
+    }
+}
+
+fn run(path: &str, query: &str) -> Result<String, String> {
+    let input = fs::read_to_string(path).map_err(|err| format!("{}: {}", path, err))?;
+    let rules = Rules::parse(&input).map_err(|err| located(path, err))?;
+    Query::parse(query)
+        .and_then(|query| query.answer(&rules))
+        .map_err(|err| err.to_string())
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let (path, query) = match args.split_first() {
+        Some((path, query)) if !query.is_empty() => (path, query.join(" ")),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    match run(path, &query) {
+        Ok(answer) => {
+            print!("{}", answer);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}\n{}", err, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,247 @@
+pub mod query;
+
+use aoc::{
+    graph::{Cycle, Graph, NodeId},
+    parse::{
+        self, integer, keyword, lines, opt, pair, separated, space, tag, take_while1, terminated,
+        Input, PResult, Parser,
+    },
+    Error, Result, Solution,
+};
+
+/// A two word colour, e.g. `shiny gold`.
+pub fn bag<'a>(input: Input<'a>) -> PResult<'a, &'a str> {
+    let color = || take_while1("a colour", |x| x.is_ascii_alphabetic());
+    let (_, rest) = pair(terminated(color(), tag(" ")), color()).parse(input)?;
+    let len = input.rest().len() - rest.rest().len();
+    Ok((&input.rest()[..len], rest))
+}
+
+fn content<'a>(input: Input<'a>) -> PResult<'a, (usize, &'a str)> {
+    pair(
+        terminated(integer(), space()),
+        terminated(bag, keyword([(" bags", ()), (" bag", ())])),
+    )
+    .parse(input)
+}
+
+fn rule<'a>(input: Input<'a>) -> PResult<'a, (&'a str, Vec<(usize, &'a str)>)> {
+    let (name, input) = terminated(bag, tag(" bags contain ")).parse(input)?;
+    let (contents, input) = match opt(tag("no other bags")).parse(input)? {
+        (Some(_), input) => (Vec::new(), input),
+        (None, input) => separated(content, tag(", ")).parse(input)?,
+    };
+    let (_, input) = tag(".").parse(input)?;
+    Ok(((name, contents), input))
+}
+
+/// The bags in a path, each with how many of it the previous bag holds.
+pub type Path<'a> = Vec<(usize, &'a str)>;
+
+/// The rules as a graph from each bag to the bags it directly contains, with
+/// the answers in both directions computed once up front.
+pub struct Rules<'a> {
+    graph: Graph<&'a str, usize>,
+    /// The number of bags inside each bag, or `None` if it overflows.
+    inside: Vec<Option<usize>>,
+    /// A bitset per bag of itself and every bag that eventually contains it.
+    outside: Vec<Vec<u64>>,
+}
+
+impl<'a> Rules<'a> {
+    pub fn parse(input: &'a str) -> Result<Self> {
+        let mut graph = Graph::new();
+        for (name, contents) in parse::complete(lines(rule), input)? {
+            let name = graph.intern(name);
+            for (count, color) in contents {
+                let color = graph.intern(color);
+                graph.connect(name, color, count);
+            }
+        }
+
+        let inside = graph
+            .fold(|_, contents| {
+                contents.into_iter().try_fold(
+                    0usize,
+                    |total, (&n, &x): (&usize, &Option<usize>)| {
+                        total.checked_add(n.checked_mul(x?.checked_add(1)?)?)
+                    },
+                )
+            })
+            .map_err(|err| cycle(&graph, err))?;
+        let words = graph.len().div_ceil(64);
+        let outside = graph
+            .reversed()
+            .fold(|bag, containers| {
+                let mut set = vec![0u64; words];
+                set[bag.index() / 64] |= 1 << (bag.index() % 64);
+                for (_, container) in containers {
+                    set.iter_mut().zip(container).for_each(|(x, y)| *x |= y);
+                }
+                set
+            })
+            .map_err(|err| cycle(&graph, err))?;
+        Ok(Self {
+            graph,
+            inside,
+            outside,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.graph.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+
+    fn id(&self, bag: &str) -> Result<NodeId> {
+        self.graph
+            .id(bag)
+            .ok_or_else(|| Error::invalid(format!("unknown bag \"{}\"", bag)))
+    }
+
+    fn can_contain(&self, container: NodeId, bag: NodeId) -> bool {
+        self.outside[bag.index()][container.index() / 64] >> (container.index() % 64) & 1 == 1
+    }
+
+    /// The number of bags inside `bag`.
+    pub fn inside(&self, bag: &str) -> Result<usize> {
+        self.inside[self.id(bag)?.index()]
+            .ok_or_else(|| Error::invalid(format!("too many bags inside \"{}\"", bag)))
+    }
+
+    /// The bags that eventually contain `bag`, sorted by name.
+    pub fn containers(&self, bag: &str) -> Result<Vec<&'a str>> {
+        let id = self.id(bag)?;
+        let mut containers = self
+            .graph
+            .ids()
+            .filter(|&x| x != id && self.can_contain(x, id))
+            .map(|x| *self.graph.node(x))
+            .collect::<Vec<_>>();
+        containers.sort_unstable();
+        Ok(containers)
+    }
+
+    /// Every way to reach `to` by opening bags starting from `from`.
+    pub fn paths(&self, from: &str, to: &str) -> Result<Vec<Path<'a>>> {
+        fn extend<'a>(
+            rules: &Rules<'a>,
+            bag: NodeId,
+            to: NodeId,
+            path: &mut Path<'a>,
+            paths: &mut Vec<Path<'a>>,
+        ) {
+            if bag == to {
+                paths.push(path.clone());
+                return;
+            }
+            for &(next, count) in rules.graph.edges(bag) {
+                if rules.can_contain(next, to) {
+                    path.push((count, *rules.graph.node(next)));
+                    extend(rules, next, to, path, paths);
+                    path.pop();
+                }
+            }
+        }
+
+        let (from, to) = (self.id(from)?, self.id(to)?);
+        let mut paths = Vec::new();
+        extend(self, from, to, &mut Vec::new(), &mut paths);
+        Ok(paths)
+    }
+}
+
+fn cycle(rules: &Graph<&str, usize>, Cycle(bags): Cycle) -> Error {
+    let bags = bags
+        .iter()
+        .chain(bags.first())
+        .map(|&bag| *rules.node(bag))
+        .collect::<Vec<_>>();
+    Error::invalid(format!("bags contain themselves: {}", bags.join(" -> ")))
+}
+
+fn part_one(input: &str) -> Result<usize> {
+    let rules = Rules::parse(input)?;
+    match rules.graph.id("shiny gold") {
+        Some(_) => Ok(rules.containers("shiny gold")?.len()),
+        None => Ok(0),
+    }
+}
+
+fn part_two(input: &str) -> Result<usize> {
+    let rules = Rules::parse(input)?;
+    match rules.graph.id("shiny gold") {
+        Some(_) => rules.inside("shiny gold"),
+        None => Ok(0),
+    }
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2020, 7).part(part_one).part(part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r"light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.";
+
+    #[test]
+    fn one() {
+        let rules = Rules::parse(INPUT).unwrap();
+        assert_eq!(rules.len(), 9);
+        assert_eq!(
+            rules.containers("shiny gold"),
+            Ok(vec![
+                "bright white",
+                "dark orange",
+                "light red",
+                "muted yellow"
+            ])
+        );
+        assert_eq!(rules.containers("light red"), Ok(vec![]));
+        assert!(rules.containers("dark red").is_err());
+
+        assert_eq!(part_one(INPUT), Ok(4));
+    }
+
+    #[test]
+    fn two() {
+        assert_eq!(part_two(INPUT), Ok(32));
+        let rules = Rules::parse(INPUT).unwrap();
+        assert_eq!(rules.inside("dark olive"), Ok(7));
+        assert_eq!(
+            rules.paths("light red", "shiny gold"),
+            Ok(vec![
+                vec![(1, "bright white"), (1, "shiny gold")],
+                vec![(2, "muted yellow"), (2, "shiny gold")]
+            ])
+        );
+        assert_eq!(rules.paths("shiny gold", "light red"), Ok(vec![]));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_one("light red bags contain 1 bright white bag").is_err());
+        assert!(part_one("light red bags contain some bright white bags.").is_err());
+        assert_eq!(
+            part_two(
+                "shiny gold bags contain 1 dark red bag.\ndark red bags contain 2 shiny gold bags."
+            ),
+            Err(Error::invalid(
+                "bags contain themselves: shiny gold -> dark red -> shiny gold"
+            ))
+        );
+    }
+}
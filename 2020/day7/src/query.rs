@@ -0,0 +1,123 @@
+//! Queries over the rules, e.g. `count inside "dark red"` or
+//! `paths from "light red" to "shiny gold"`. Quotes around bags are optional.
+
+use super::{bag, Rules};
+use aoc::{
+    parse::{self, keyword, opt, tag, terminated, Input, PResult, Parser},
+    Result,
+};
+use std::fmt::Write;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Query<'a> {
+    /// The number of bags inside a bag.
+    Inside(&'a str),
+    /// The number of bags that eventually contain a bag.
+    Containing(&'a str),
+    /// The bags that eventually contain a bag.
+    Containers(&'a str),
+    /// Every way to reach a bag by opening another.
+    Paths { from: &'a str, to: &'a str },
+}
+
+#[derive(Copy, Clone)]
+enum Command {
+    Inside,
+    Containing,
+    Containers,
+    Paths,
+}
+
+fn quoted<'a>(input: Input<'a>) -> PResult<'a, &'a str> {
+    match opt(tag("\"")).parse(input)? {
+        (Some(_), input) => terminated(bag, tag("\"")).parse(input),
+        (None, input) => bag(input),
+    }
+}
+
+fn query<'a>(input: Input<'a>) -> PResult<'a, Query<'a>> {
+    let (command, input) = keyword([
+        ("count inside ", Command::Inside),
+        ("count containing ", Command::Containing),
+        ("list containing ", Command::Containers),
+        ("paths from ", Command::Paths),
+    ])
+    .parse(input)?;
+    let (bag, input) = quoted(input)?;
+    Ok(match command {
+        Command::Inside => (Query::Inside(bag), input),
+        Command::Containing => (Query::Containing(bag), input),
+        Command::Containers => (Query::Containers(bag), input),
+        Command::Paths => {
+            let (_, input) = tag(" to ").parse(input)?;
+            let (to, input) = quoted(input)?;
+            (Query::Paths { from: bag, to }, input)
+        }
+    })
+}
+
+impl<'a> Query<'a> {
+    pub fn parse(input: &'a str) -> Result<Self> {
+        Ok(parse::complete(query, input)?)
+    }
+
+    /// The answer to the query, followed by a newline.
+    pub fn answer(&self, rules: &Rules) -> Result<String> {
+        let mut output = String::new();
+        match *self {
+            Query::Inside(bag) => {
+                let _ = writeln!(output, "{}", rules.inside(bag)?);
+            }
+            Query::Containing(bag) => {
+                let _ = writeln!(output, "{}", rules.containers(bag)?.len());
+            }
+            Query::Containers(bag) => {
+                for container in rules.containers(bag)? {
+                    let _ = writeln!(output, "{}", container);
+                }
+            }
+            Query::Paths { from, to } => {
+                for path in rules.paths(from, to)? {
+                    let _ = write!(output, "{}", from);
+                    for (count, bag) in path {
+                        let _ = write!(output, " -> {} {}", count, bag);
+                    }
+                    let _ = writeln!(output);
+                }
+            }
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queries() {
+        let rules = Rules::parse(
+            "light red bags contain 1 bright white bag, 2 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags.
+shiny gold bags contain 3 dark red bags.",
+        )
+        .unwrap();
+        let answer = |query| Query::parse(query).and_then(|query| query.answer(&rules));
+        assert_eq!(answer("count inside \"light red\""), Ok("23\n".to_string()));
+        assert_eq!(answer("count containing shiny gold"), Ok("3\n".to_string()));
+        assert_eq!(
+            answer("list containing \"bright white\""),
+            Ok("light red\n".to_string())
+        );
+        assert_eq!(
+            answer("paths from \"light red\" to \"shiny gold\""),
+            Ok("light red -> 1 bright white -> 1 shiny gold\n\
+                light red -> 2 muted yellow -> 2 shiny gold\n"
+                .to_string())
+        );
+        assert!(answer("count inside \"dark blue\"").is_err());
+        assert!(answer("count inside \"dark red").is_err());
+        assert!(answer("paths from light red").is_err());
+    }
+}
@@ -0,0 +1,48 @@
+use day8::Console;
+use std::{env, fs, process::ExitCode};
+
+const USAGE: &str = "usage: console (trace|repair|disassemble|dot) <input>";
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let (command, path) = match args.as_slice() {
+        [command, path] => (command.as_str(), path),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    let console = match fs::read_to_string(path)
+        .map_err(|err| format!("{}: {}", path, err))
+        .and_then(|input| {
+            input
+                .parse::<Console>()
+                .map_err(|err| format!("{}:{}", path, err))
+        }) {
+        Ok(console) => console,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    match command {
+        "trace" => println!("{}", console.trace()),
+        "repair" => match console.repair() {
+            Ok(repair) => println!(
+                "flipping instruction {} terminates with accumulator {}",
+                repair.index, repair.accumulator
+            ),
+            Err(err) => {
+                eprintln!("error: {}", err);
+                return ExitCode::FAILURE;
+            }
+        },
+        "disassemble" => print!("{}", console.disassemble()),
+        "dot" => print!("{}", console.dot()),
+        command => {
+            eprintln!("error: unknown command `{}`\n{}", command, USAGE);
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
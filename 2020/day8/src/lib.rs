@@ -0,0 +1,520 @@
+use aoc::{
+    parse::{self, integer, keyword, lines, space, terminated, Input, PResult, Parser},
+    Error, Result, Solution,
+};
+use std::{
+    convert::TryFrom,
+    fmt::{self, Write},
+    str::FromStr,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Operation {
+    Accumulate,
+    Jump,
+    Nop,
+}
+
+/// What executing an instruction does with its argument.
+struct Effect {
+    accumulate: isize,
+    jump: isize,
+}
+
+struct Opcode {
+    mnemonic: &'static str,
+    operation: Operation,
+    execute: fn(isize) -> Effect,
+    /// The operation this one may have been corrupted from, when repairing.
+    flip: Option<Operation>,
+}
+
+/// How each `Operation` is written, executed and repaired. Parsing, execution
+/// and repair all go through this table, so a new operation needs a variant
+/// and an entry here.
+const OPCODES: [Opcode; 3] = [
+    Opcode {
+        mnemonic: "acc",
+        operation: Operation::Accumulate,
+        execute: |argument| Effect {
+            accumulate: argument,
+            jump: 1,
+        },
+        flip: None,
+    },
+    Opcode {
+        mnemonic: "jmp",
+        operation: Operation::Jump,
+        execute: |argument| Effect {
+            accumulate: 0,
+            jump: argument,
+        },
+        flip: Some(Operation::Nop),
+    },
+    Opcode {
+        mnemonic: "nop",
+        operation: Operation::Nop,
+        execute: |_| Effect {
+            accumulate: 0,
+            jump: 1,
+        },
+        flip: Some(Operation::Jump),
+    },
+];
+
+impl Operation {
+    fn opcode(self) -> &'static Opcode {
+        OPCODES
+            .iter()
+            .find(|opcode| opcode.operation == self)
+            .expect("every operation has an opcode")
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Instruction {
+    operation: Operation,
+    argument: isize,
+}
+
+impl Instruction {
+    fn execute(self) -> Effect {
+        (self.operation.opcode().execute)(self.argument)
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {:+}",
+            self.operation.opcode().mnemonic,
+            self.argument
+        )
+    }
+}
+
+fn instruction(input: Input) -> PResult<Instruction> {
+    let (operation, input) = terminated(
+        keyword(OPCODES.map(|opcode| (opcode.mnemonic, opcode.operation))),
+        space(),
+    )
+    .parse(input)?;
+    let (argument, input) = integer().parse(input)?;
+    Ok((
+        Instruction {
+            operation,
+            argument,
+        },
+        input,
+    ))
+}
+
+/// The instruction flipped to repair the boot code, and the accumulator once it
+/// terminates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Repair {
+    pub index: usize,
+    pub accumulator: isize,
+}
+
+/// An executed instruction, with the accumulator after it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Step {
+    pub program_counter: usize,
+    instruction: Instruction,
+    pub accumulator: isize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Exit {
+    /// Reached the instruction right after the boot code.
+    Terminated,
+    /// About to execute `entry` a second time, after `body` since the first.
+    Loop { entry: usize, body: Vec<usize> },
+    /// Jumped outside the boot code.
+    OutOfBounds(isize),
+    /// The accumulator or jump target of the instruction at this index does
+    /// not fit an `isize`.
+    Overflow(usize),
+}
+
+/// The executed instructions until the boot code terminates or loops.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trace {
+    pub steps: Vec<Step>,
+    pub exit: Exit,
+}
+
+impl Trace {
+    pub fn accumulator(&self) -> isize {
+        self.steps.last().map_or(0, |step| step.accumulator)
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{:>6}  {:<10} {:>8}", "pc", "op", "acc")?;
+        for step in &self.steps {
+            writeln!(
+                f,
+                "{:>6}  {:<10} {:>8}",
+                step.program_counter,
+                step.instruction.to_string(),
+                step.accumulator
+            )?;
+        }
+        match &self.exit {
+            Exit::Terminated => write!(f, "terminated"),
+            Exit::Loop { entry, body } => write!(
+                f,
+                "loop entered at {} through {} instructions",
+                entry,
+                body.len()
+            ),
+            Exit::OutOfBounds(target) => write!(f, "jump to {} outside the boot code", target),
+            Exit::Overflow(index) => write!(f, "overflow executing {}", index),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Console {
+    boot_code: Vec<Instruction>,
+}
+
+impl Console {
+    fn with_boot_code(mut self, boot_code: Vec<Instruction>) -> Self {
+        self.boot_code = boot_code;
+        self
+    }
+
+    /// Where executing `operation` at `program_counter` jumps to, if that fits
+    /// an `isize`.
+    fn target(&self, program_counter: usize, operation: Operation) -> Option<isize> {
+        let instruction = Instruction {
+            operation,
+            ..self.boot_code[program_counter]
+        };
+        isize::try_from(program_counter)
+            .ok()?
+            .checked_add(instruction.execute().jump)
+    }
+
+    /// The program counter after executing `operation` at `program_counter`,
+    /// if it stays within the boot code or right after its end.
+    fn next(&self, program_counter: usize, operation: Operation) -> Option<usize> {
+        self.target(program_counter, operation)
+            .and_then(|target| usize::try_from(target).ok())
+            .filter(|&next| next <= self.boot_code.len())
+    }
+
+    /// Finds the single instruction to flip, `jmp` to `nop` or back, that makes
+    /// the boot code terminate.
+    ///
+    /// Instructions that reach the end are found by walking back from it, so
+    /// only the instructions the original boot code executes have to be tried.
+    pub fn repair(&self) -> Result<Repair> {
+        let len = self.boot_code.len();
+        let mut sources = vec![Vec::new(); len + 1];
+        for program_counter in 0..len {
+            let operation = self.boot_code[program_counter].operation;
+            if let Some(next) = self.next(program_counter, operation) {
+                sources[next].push(program_counter);
+            }
+        }
+        let mut terminates = vec![false; len + 1];
+        let mut stack = vec![len];
+        while let Some(program_counter) = stack.pop() {
+            if !std::mem::replace(&mut terminates[program_counter], true) {
+                stack.extend(&sources[program_counter]);
+            }
+        }
+        if terminates[0] {
+            return Err(Error::invalid("boot code already terminates"));
+        }
+
+        let mut seen = vec![false; len];
+        let mut program_counter = 0;
+        let (index, flipped) = loop {
+            if program_counter == len || std::mem::replace(&mut seen[program_counter], true) {
+                return Err(Error::invalid(
+                    "no single flip makes the boot code terminate",
+                ));
+            }
+            let operation = self.boot_code[program_counter].operation;
+            if let Some(flipped) = operation.opcode().flip {
+                if self
+                    .next(program_counter, flipped)
+                    .is_some_and(|next| terminates[next])
+                {
+                    break (program_counter, flipped);
+                }
+            }
+            program_counter = self.next(program_counter, operation).ok_or_else(|| {
+                Error::invalid(format!("jump outside the boot code at {}", program_counter))
+            })?;
+        };
+
+        let mut boot_code = self.boot_code.clone();
+        boot_code[index].operation = flipped;
+        Ok(Repair {
+            index,
+            accumulator: Console::default().with_boot_code(boot_code).run()?,
+        })
+    }
+
+    /// Executes the boot code until it terminates, jumps outside of it,
+    /// overflows or is about to repeat an instruction.
+    pub fn trace(&self) -> Trace {
+        let mut seen = vec![None; self.boot_code.len()];
+        let mut steps = Vec::new();
+        let (mut program_counter, mut accumulator) = (0isize, 0isize);
+        let exit = loop {
+            let index = match usize::try_from(program_counter) {
+                Ok(index) if index == self.boot_code.len() => break Exit::Terminated,
+                Ok(index) if index < self.boot_code.len() => index,
+                _ => break Exit::OutOfBounds(program_counter),
+            };
+            if let Some(first) = seen[index] {
+                break Exit::Loop {
+                    entry: index,
+                    body: steps[first..]
+                        .iter()
+                        .map(|step: &Step| step.program_counter)
+                        .collect(),
+                };
+            }
+            seen[index] = Some(steps.len());
+            let instruction = self.boot_code[index];
+            let effect = instruction.execute();
+            match (
+                accumulator.checked_add(effect.accumulate),
+                program_counter.checked_add(effect.jump),
+            ) {
+                (Some(next_accumulator), Some(next_program_counter)) => {
+                    accumulator = next_accumulator;
+                    program_counter = next_program_counter;
+                }
+                _ => break Exit::Overflow(index),
+            }
+            steps.push(Step {
+                program_counter: index,
+                instruction,
+                accumulator,
+            });
+        };
+        Trace { steps, exit }
+    }
+
+    /// The accumulator when the boot code terminates or starts to loop.
+    fn run(self) -> Result<isize> {
+        let trace = self.trace();
+        match trace.exit {
+            Exit::OutOfBounds(target) => Err(Error::invalid(format!(
+                "jump to {} outside the boot code",
+                target
+            ))),
+            Exit::Overflow(index) => Err(Error::invalid(format!(
+                "overflow executing {} at {}",
+                self.boot_code[index], index
+            ))),
+            _ => Ok(trace.accumulator()),
+        }
+    }
+
+    /// The boot code with an address before each instruction.
+    pub fn disassemble(&self) -> String {
+        self.boot_code
+            .iter()
+            .enumerate()
+            .map(|(address, instruction)| format!("{:04}  {}\n", address, instruction))
+            .collect()
+    }
+
+    /// The control flow graph in Graphviz DOT format, with an edge from every
+    /// instruction to the next one and the instructions of `trace`'s loop in red.
+    pub fn dot(&self) -> String {
+        let body = match self.trace().exit {
+            Exit::Loop { body, .. } => body,
+            _ => Vec::new(),
+        };
+        let mut output = String::from("digraph boot {\n  node [shape=box];\n");
+        for (address, instruction) in self.boot_code.iter().enumerate() {
+            let color = if body.contains(&address) {
+                " color=red"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                output,
+                "  {} [label=\"{}: {}\"{}];",
+                address, address, instruction, color
+            );
+            let _ = match self.next(address, instruction.operation) {
+                Some(next) if next == self.boot_code.len() => {
+                    writeln!(output, "  {} -> end;", address)
+                }
+                Some(next) => writeln!(output, "  {} -> {};", address, next),
+                None => writeln!(
+                    output,
+                    "  {} -> \"{}\" [style=dashed];",
+                    address,
+                    self.target(address, instruction.operation)
+                        .map_or_else(|| "overflow".to_string(), |target| target.to_string())
+                ),
+            };
+        }
+        output.push_str("  end [shape=doublecircle];\n}\n");
+        output
+    }
+}
+
+fn parse_boot_code(input: &str) -> Result<Vec<Instruction>> {
+    Ok(parse::complete(lines(instruction), input)?)
+}
+
+impl FromStr for Console {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Console::default().with_boot_code(parse_boot_code(s)?))
+    }
+}
+
+fn part_one(input: &str) -> Result<isize> {
+    Console::default()
+        .with_boot_code(parse_boot_code(input)?)
+        .run()
+}
+
+fn part_two(input: &str) -> Result<isize> {
+    Ok(Console::default()
+        .with_boot_code(parse_boot_code(input)?)
+        .repair()?
+        .accumulator)
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2020, 8).part(part_one).part(part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r"nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+    #[test]
+    fn one() {
+        assert_eq!(part_one(INPUT), Ok(5));
+    }
+
+    #[test]
+    fn two() {
+        assert_eq!(part_two(INPUT), Ok(8));
+    }
+
+    #[test]
+    fn repair() {
+        let console = Console::default().with_boot_code(parse_boot_code(INPUT).unwrap());
+        assert_eq!(
+            console.repair(),
+            Ok(Repair {
+                index: 7,
+                accumulator: 8
+            })
+        );
+        let repair = |input| {
+            Console::default()
+                .with_boot_code(parse_boot_code(input).unwrap())
+                .repair()
+        };
+        assert_eq!(
+            repair("jmp +2\nacc +5\njmp -2"),
+            Ok(Repair {
+                index: 2,
+                accumulator: 0
+            })
+        );
+        assert!(repair("acc +1\nnop +0").is_err());
+        assert!(repair("acc +1\njmp -1\njmp -2").is_err());
+    }
+
+    #[test]
+    fn trace() {
+        let console = INPUT.parse::<Console>().unwrap();
+        let trace = console.trace();
+        assert_eq!(
+            trace.exit,
+            Exit::Loop {
+                entry: 1,
+                body: vec![1, 2, 6, 7, 3, 4]
+            }
+        );
+        assert_eq!(trace.accumulator(), 5);
+        assert_eq!(
+            trace.to_string().lines().nth(4),
+            Some("     6  acc +1            2")
+        );
+        assert!(trace
+            .to_string()
+            .ends_with("loop entered at 1 through 6 instructions"));
+        assert_eq!(
+            "acc +1\nacc -2".parse::<Console>().unwrap().trace().exit,
+            Exit::Terminated
+        );
+        assert_eq!(
+            "jmp -1".parse::<Console>().unwrap().trace().exit,
+            Exit::OutOfBounds(-1)
+        );
+    }
+
+    #[test]
+    fn listings() {
+        let console = INPUT.parse::<Console>().unwrap();
+        assert_eq!(console.disassemble(), "0000  nop +0\n0001  acc +1\n0002  jmp +4\n0003  acc +3\n0004  jmp -3\n0005  acc -99\n0006  acc +1\n0007  jmp -4\n0008  acc +6\n");
+        let dot = console.dot();
+        assert!(dot.contains("  7 [label=\"7: jmp -4\" color=red];\n  7 -> 3;\n"));
+        assert!(dot.contains("  8 -> end;\n"));
+        assert!("jmp +5"
+            .parse::<Console>()
+            .unwrap()
+            .dot()
+            .contains("  0 -> \"5\" [style=dashed];\n"));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_one("nop +0\nacc 1x").is_err());
+        assert!(part_one("mul +2").is_err());
+        assert!(part_one("jmp -1").is_err());
+    }
+
+    #[test]
+    fn overflow() {
+        for input in [
+            "nop +0\njmp +9223372036854775807",
+            "acc +9223372036854775807\nacc +1",
+        ] {
+            let console = input.parse::<Console>().unwrap();
+            assert_eq!(console.trace().exit, Exit::Overflow(1));
+            assert!(part_one(input).is_err());
+            assert!(console.dot().starts_with("digraph"));
+        }
+        assert_eq!(part_two("nop +0\njmp +9223372036854775807"), Ok(0));
+        assert!(part_two("jmp +0\nacc +9223372036854775807\nacc +1").is_err());
+        assert!("nop +0\njmp +9223372036854775807"
+            .parse::<Console>()
+            .unwrap()
+            .dot()
+            .contains("  1 -> \"overflow\" [style=dashed];\n"));
+    }
+}
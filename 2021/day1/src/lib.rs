@@ -0,0 +1,130 @@
+use aoc::{
+    parse::{self, integer, lines},
+    Error, Result, Solution,
+};
+use std::{collections::VecDeque, ops::Sub};
+
+/// Pairs each reading with the one `w` readings before it, oldest first. Sums
+/// of `w` consecutive readings share all but their first and last reading, so
+/// comparing these pairs compares neighbouring windows, keeping only the last
+/// `w` readings around.
+pub fn changes<T: Copy>(
+    readings: impl IntoIterator<Item = T>,
+    w: usize,
+) -> Result<impl Iterator<Item = (T, T)>> {
+    if w == 0 {
+        return Err(Error::invalid("windows need at least one reading"));
+    }
+    let mut window = VecDeque::with_capacity(w);
+    Ok(readings.into_iter().filter_map(move |reading| {
+        window.push_back(reading);
+        (window.len() > w).then(|| (window.pop_front().unwrap(), reading))
+    }))
+}
+
+/// The number of windows of `w` readings with a larger sum than the previous.
+pub fn increases<T: Copy + PartialOrd>(
+    readings: impl IntoIterator<Item = T>,
+    w: usize,
+) -> Result<usize> {
+    Ok(changes(readings, w)?
+        .filter(|(oldest, newest)| newest > oldest)
+        .count())
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats<T> {
+    pub increases: usize,
+    /// The most increases in a row.
+    pub longest_run: usize,
+    /// The largest decrease from one window to the next, if any.
+    pub largest_drop: Option<T>,
+}
+
+pub fn stats<T: Copy + PartialOrd + Sub<Output = T>>(
+    readings: impl IntoIterator<Item = T>,
+    w: usize,
+) -> Result<Stats<T>> {
+    let mut run = 0;
+    let mut stats = Stats {
+        increases: 0,
+        longest_run: 0,
+        largest_drop: None,
+    };
+    for (oldest, newest) in changes(readings, w)? {
+        if newest > oldest {
+            stats.increases += 1;
+            run += 1;
+            stats.longest_run = stats.longest_run.max(run);
+        } else {
+            run = 0;
+        }
+        if newest < oldest {
+            let drop = oldest - newest;
+            if stats.largest_drop.is_none_or(|largest| drop > largest) {
+                stats.largest_drop = Some(drop);
+            }
+        }
+    }
+    Ok(stats)
+}
+
+fn parse(input: &str) -> Result<Vec<u32>> {
+    Ok(parse::complete(lines(integer()), input)?)
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2021, 1)
+        .part(|input| increases(parse(input)?, 1))
+        .part(|input| increases(parse(input)?, 3))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[u32] = &[199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn one() {
+        assert_eq!(increases(INPUT.iter().copied(), 1), Ok(7));
+    }
+
+    #[test]
+    fn two() {
+        assert_eq!(increases(INPUT.iter().copied(), 3), Ok(5));
+        assert_eq!(
+            changes(INPUT.iter().copied(), 3)
+                .unwrap()
+                .map(|(oldest, newest)| i64::from(newest) - i64::from(oldest))
+                .collect::<Vec<_>>(),
+            [11, 0, -1, 30, 69, 53, 23]
+        );
+        assert_eq!(increases(INPUT.iter().copied(), 11), Ok(0));
+        assert_eq!(increases([u64::MAX - 1, 0, u64::MAX], 2), Ok(1));
+        assert!(increases(INPUT.iter().copied(), 0).is_err());
+    }
+
+    #[test]
+    fn statistics() {
+        assert_eq!(
+            stats(INPUT.iter().copied(), 1),
+            Ok(Stats {
+                increases: 7,
+                longest_run: 3,
+                largest_drop: Some(10)
+            })
+        );
+        assert_eq!(stats::<u16>([1, 2, 3], 3), Ok(Stats::default()));
+        assert_eq!(
+            stats([u64::MAX, 0], 1).map(|stats| stats.largest_drop),
+            Ok(Some(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(parse("199\n200\n-208").is_err());
+        assert!(parse("199\n2OO").is_err());
+    }
+}
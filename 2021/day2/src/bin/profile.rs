@@ -0,0 +1,54 @@
+use aoc_2021_day2::{commands, render, trajectory, MotionModel, Point, Position, Track};
+use std::{env, error::Error, fs::File, io::BufReader, process::ExitCode};
+
+const USAGE: &str = "usage: profile [--aim] [--svg] <input>";
+
+/// Prefixes `err` with `path`, joined like `path:line:column` for parse errors.
+fn located(path: &str, err: aoc::Error) -> String {
+    match err {
+        aoc::Error::Parse(_) => format!("{}:{}", path, err),
+        aoc::Error::Invalid(_) => format!("{}: {}", path, err),
+    }
+}
+
+fn points<M: MotionModel>(path: &str) -> Result<Vec<Point>, Box<dyn Error>> {
+    let reader = BufReader::new(File::open(path).map_err(|err| format!("{}: {}", path, err))?);
+    trajectory::<M>(commands(reader))
+        .collect::<aoc::Result<_>>()
+        .map_err(|err| located(path, err).into())
+}
+
+fn profile(args: impl Iterator<Item = String>) -> Result<String, Box<dyn Error>> {
+    let (mut aim, mut svg, mut path) = (false, false, None);
+    for arg in args {
+        match arg.as_str() {
+            "--aim" => aim = true,
+            "--svg" => svg = true,
+            flag if flag.starts_with("--") => return Err(format!("unknown flag `{}`", flag).into()),
+            _ if path.is_none() => path = Some(arg),
+            _ => return Err("expected a single input path".into()),
+        }
+    }
+    let path = path.ok_or("missing input path")?;
+    let points = match aim {
+        true => points::<Track>(&path)?,
+        false => points::<Position>(&path)?,
+    };
+    Ok(match svg {
+        true => render::svg(&points),
+        false => render::ascii(&points, 72, 20),
+    })
+}
+
+fn main() -> ExitCode {
+    match profile(env::args().skip(1)) {
+        Ok(output) => {
+            print!("{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}\n{}", err, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,225 @@
+pub mod render;
+
+use aoc::{
+    parse::{self, integer, keyword, preceded, space, Error, Input, PResult, Parser},
+    Solution,
+};
+use std::{io::BufRead, str::FromStr};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Command {
+    Forward(u32),
+    Up(u32),
+    Down(u32),
+}
+
+fn command(input: Input) -> PResult<Command> {
+    let (direction, input) = keyword([
+        ("forward", Command::Forward as fn(u32) -> Command),
+        ("up", Command::Up),
+        ("down", Command::Down),
+    ])
+    .parse(input)?;
+    let (distance, input) = preceded(space(), integer()).parse(input)?;
+    Ok((direction(distance), input))
+}
+
+impl FromStr for Command {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(command, s)
+    }
+}
+
+/// Reads one command per line, without holding on to the input.
+pub fn commands(reader: impl BufRead) -> impl Iterator<Item = aoc::Result<Command>> {
+    reader.lines().enumerate().map(|(idx, line)| {
+        let line = line.map_err(|err| aoc::Error::invalid(format!("line {}: {}", idx + 1, err)))?;
+        Ok(line.parse().map_err(|err: Error| err.with_line(idx + 1))?)
+    })
+}
+
+/// A position with depth growing downwards, which may go above the surface.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Point {
+    pub horizontal: i64,
+    pub depth: i64,
+}
+
+/// How the submarine interprets commands.
+pub trait MotionModel: Default {
+    /// Fails if the position no longer fits an `i64`.
+    fn apply(&mut self, command: Command) -> aoc::Result<()>;
+
+    fn position(&self) -> Point;
+}
+
+/// `up` and `down` change the depth directly.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Position(Point);
+
+fn overflow() -> aoc::Error {
+    aoc::Error::invalid("the submarine went out of range")
+}
+
+impl MotionModel for Position {
+    fn apply(&mut self, command: Command) -> aoc::Result<()> {
+        let Point { horizontal, depth } = &mut self.0;
+        let (value, delta) = match command {
+            Command::Forward(distance) => (horizontal, i64::from(distance)),
+            Command::Up(units) => (depth, -i64::from(units)),
+            Command::Down(units) => (depth, i64::from(units)),
+        };
+        *value = value.checked_add(delta).ok_or_else(overflow)?;
+        Ok(())
+    }
+
+    fn position(&self) -> Point {
+        self.0
+    }
+}
+
+/// `up` and `down` change the aim, and moving forward dives along it.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Track {
+    point: Point,
+    aim: i64,
+}
+
+impl MotionModel for Track {
+    fn apply(&mut self, command: Command) -> aoc::Result<()> {
+        match command {
+            Command::Forward(distance) => {
+                let distance = i64::from(distance);
+                let horizontal = self.point.horizontal.checked_add(distance);
+                let depth = self
+                    .aim
+                    .checked_mul(distance)
+                    .and_then(|dive| self.point.depth.checked_add(dive));
+                self.point = Point {
+                    horizontal: horizontal.ok_or_else(overflow)?,
+                    depth: depth.ok_or_else(overflow)?,
+                };
+            }
+            Command::Up(units) => {
+                self.aim = self
+                    .aim
+                    .checked_sub(i64::from(units))
+                    .ok_or_else(overflow)?;
+            }
+            Command::Down(units) => {
+                self.aim = self
+                    .aim
+                    .checked_add(i64::from(units))
+                    .ok_or_else(overflow)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn position(&self) -> Point {
+        self.point
+    }
+}
+
+/// The starting point followed by the position after each command, stopping
+/// after the first error or overflow.
+pub fn trajectory<M: MotionModel>(
+    commands: impl IntoIterator<Item = aoc::Result<Command>>,
+) -> impl Iterator<Item = aoc::Result<Point>> {
+    let mut model = M::default();
+    let mut failed = false;
+    std::iter::once(Ok(model.position())).chain(commands.into_iter().map_while(move |command| {
+        match command {
+            _ if failed => None,
+            Ok(command) => match model.apply(command) {
+                Ok(()) => Some(Ok(model.position())),
+                Err(err) => {
+                    failed = true;
+                    Some(Err(err))
+                }
+            },
+            Err(err) => {
+                failed = true;
+                Some(Err(err))
+            }
+        }
+    }))
+}
+
+/// The product of the final horizontal position and depth.
+fn dive<M: MotionModel>(input: &str) -> aoc::Result<i64> {
+    let end = trajectory::<M>(commands(input.as_bytes())).try_fold(Point::default(), |_, x| x)?;
+    end.horizontal.checked_mul(end.depth).ok_or_else(overflow)
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2021, 2)
+        .part(dive::<Position>)
+        .part(dive::<Track>)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2
+"#;
+
+    #[test]
+    fn one() {
+        assert_eq!(dive::<Position>(INPUT), Ok(150));
+        assert_eq!(dive::<Position>("forward 2\nup 3"), Ok(-6));
+    }
+
+    #[test]
+    fn two() {
+        assert_eq!(dive::<Track>(INPUT), Ok(900));
+        assert_eq!(
+            trajectory::<Track>(commands(INPUT.as_bytes()))
+                .map(|x| x.map(|x| (x.horizontal, x.depth)))
+                .collect::<aoc::Result<Vec<_>>>(),
+            Ok(vec![
+                (0, 0),
+                (5, 0),
+                (5, 0),
+                (13, 40),
+                (13, 40),
+                (13, 40),
+                (15, 60)
+            ])
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        let input = "down 4294967295\nforward 4294967295\n";
+        assert!(dive::<Position>(input).is_err());
+        assert!(dive::<Track>(input).is_err());
+        assert_eq!(
+            trajectory::<Track>(commands(input.as_bytes())).count(),
+            3,
+            "stops at the overflow"
+        );
+        assert_eq!(
+            dive::<Position>("down 4294967295\nforward 2"),
+            Ok(8589934590)
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(dive::<Position>("forward 5\nbackward 2").is_err());
+        assert!("down -1".parse::<Command>().is_err());
+        match trajectory::<Position>(commands("up 1\ndown x\nforward 1".as_bytes())).last() {
+            Some(Err(aoc::Error::Parse(err))) => assert_eq!((err.line(), err.column()), (2, 6)),
+            other => panic!("expected a parse error, found {:?}", other),
+        }
+    }
+}
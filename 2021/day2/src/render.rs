@@ -0,0 +1,129 @@
+//! Depth profiles of a trajectory, with the horizontal position going right and
+//! the depth going down.
+
+use super::Point;
+use std::fmt::Write;
+
+/// The smallest and largest coordinates, including the surface.
+fn bounds(points: &[Point]) -> (Point, Point) {
+    points
+        .iter()
+        .fold((Point::default(), Point::default()), |(min, max), point| {
+            (
+                Point {
+                    horizontal: min.horizontal.min(point.horizontal),
+                    depth: min.depth.min(point.depth),
+                },
+                Point {
+                    horizontal: max.horizontal.max(point.horizontal),
+                    depth: max.depth.max(point.depth),
+                },
+            )
+        })
+}
+
+/// Draws the trajectory with `#` on a `width` by `height` grid, scaling each
+/// axis separately, with `~` for the surface and a line with the bounds.
+pub fn ascii(points: &[Point], width: usize, height: usize) -> String {
+    let (min, max) = bounds(points);
+    let (width, height) = (width.max(1), height.max(1));
+    // Maps a coordinate onto one of `cells` cells, keeping both ends in view.
+    let scale = |value: i64, min: i64, max: i64, cells: usize| {
+        ((value - min) as f64 / (max - min).max(1) as f64 * (cells - 1) as f64).round() as usize
+    };
+    let cell = |point: Point| {
+        (
+            scale(point.horizontal, min.horizontal, max.horizontal, width),
+            scale(point.depth, min.depth, max.depth, height),
+        )
+    };
+
+    let mut grid = vec![vec![' '; width]; height];
+    grid[cell(Point::default()).1] = vec!['~'; width];
+    for pair in points.windows(2) {
+        let ((x0, y0), (x1, y1)) = (cell(pair[0]), cell(pair[1]));
+        let steps = x0.abs_diff(x1).max(y0.abs_diff(y1)).max(1);
+        for step in 0..=steps {
+            let t = step as f64 / steps as f64;
+            let x = (x0 as f64 + (x1 as f64 - x0 as f64) * t).round() as usize;
+            let y = (y0 as f64 + (y1 as f64 - y0 as f64) * t).round() as usize;
+            grid[y][x] = '#';
+        }
+    }
+    if let [point] = points {
+        let (x, y) = cell(*point);
+        grid[y][x] = '#';
+    }
+
+    let mut output = grid
+        .into_iter()
+        .map(|row| row.into_iter().collect::<String>().trim_end().to_string() + "\n")
+        .collect::<String>();
+    let _ = writeln!(
+        output,
+        "horizontal {}..={}, depth {}..={}",
+        min.horizontal, max.horizontal, min.depth, max.depth
+    );
+    output
+}
+
+/// Draws the trajectory as a polyline below a surface line, stretched to fill
+/// the image as depths tend to dwarf horizontal distances.
+pub fn svg(points: &[Point]) -> String {
+    let (min, max) = bounds(points);
+    let (width, height) = (
+        (max.horizontal - min.horizontal).max(1),
+        (max.depth - min.depth).max(1),
+    );
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"800\" height=\"400\" viewBox=\"{} {} {} {}\" preserveAspectRatio=\"none\">",
+        min.horizontal, min.depth, width, height
+    );
+    let _ = writeln!(
+        output,
+        "  <line x1=\"{}\" y1=\"0\" x2=\"{}\" y2=\"0\" stroke=\"#1f77b4\" stroke-width=\"1\" vector-effect=\"non-scaling-stroke\"/>",
+        min.horizontal,
+        min.horizontal + width
+    );
+    let path = points
+        .iter()
+        .map(|point| format!("{},{}", point.horizontal, point.depth))
+        .collect::<Vec<_>>();
+    let _ = writeln!(
+        output,
+        "  <polyline points=\"{}\" fill=\"none\" stroke=\"black\" stroke-width=\"1\" vector-effect=\"non-scaling-stroke\"/>",
+        path.join(" ")
+    );
+    output.push_str("</svg>\n");
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{commands, trajectory, Track};
+
+    #[test]
+    fn profiles() {
+        let points = trajectory::<Track>(commands(
+            "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n".as_bytes(),
+        ))
+        .collect::<aoc::Result<Vec<_>>>()
+        .unwrap();
+        assert_eq!(
+            ascii(&points, 16, 4),
+            "\
+#######~~~~~~~~~
+       ####
+           ###
+              ##
+horizontal 0..=15, depth 0..=60
+"
+        );
+        let svg = svg(&points);
+        assert!(svg.contains("viewBox=\"0 0 15 60\""));
+        assert!(svg.contains("points=\"0,0 5,0 5,0 13,40 13,40 13,40 15,60\""));
+    }
+}
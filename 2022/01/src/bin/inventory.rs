@@ -0,0 +1,78 @@
+use aoc_2022_01::{diagnostics, top, Inventory};
+use std::{env, error::Error, fs, io::BufReader, process::ExitCode};
+
+const USAGE: &str = "usage: inventory [--top <k> | --rank <elf> | --check] <input>";
+
+enum Command {
+    Top(usize),
+    Rank(usize),
+    Check,
+}
+
+/// Prefixes `err` with `path`, joined like `path:line:column` for parse errors.
+fn located(path: &str, err: aoc::Error) -> String {
+    match err {
+        aoc::Error::Parse(_) => format!("{}:{}", path, err),
+        aoc::Error::Invalid(_) => format!("{}: {}", path, err),
+    }
+}
+
+fn run(mut args: impl Iterator<Item = String>) -> Result<bool, Box<dyn Error>> {
+    let (mut command, mut path) = (Command::Top(3), None);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for `{}`", arg));
+        match arg.as_str() {
+            "--top" => command = Command::Top(value()?.parse()?),
+            "--rank" => command = Command::Rank(value()?.parse()?),
+            "--check" => command = Command::Check,
+            flag if flag.starts_with("--") => return Err(format!("unknown flag `{}`", flag).into()),
+            _ if path.is_none() => path = Some(arg),
+            _ => return Err("expected a single input path".into()),
+        }
+    }
+    let path = path.ok_or("missing input path")?;
+    let read = || fs::read_to_string(&path).map_err(|err| format!("{}: {}", path, err));
+
+    match command {
+        Command::Top(k) => {
+            let file = fs::File::open(&path).map_err(|err| format!("{}: {}", path, err))?;
+            let elves = top(BufReader::new(file), k).map_err(|err| located(&path, err))?;
+            for (rank, elf) in (1..).zip(elves) {
+                println!("{:>4} elf {:<6} {}", rank, elf.id, elf.calories);
+            }
+        }
+        Command::Rank(id) => {
+            let inventory = Inventory::parse(&read()?).map_err(|err| located(&path, err))?;
+            let (elf, rank) = inventory
+                .elf(id)
+                .zip(inventory.rank(id))
+                .ok_or(format!("there is no elf {}", id))?;
+            println!(
+                "elf {} is ranked {} of {} with {} calories",
+                id,
+                rank,
+                inventory.elves().len(),
+                elf.calories
+            );
+        }
+        Command::Check => {
+            let diagnostics = diagnostics(&read()?);
+            for err in &diagnostics {
+                eprintln!("{}:{}", path, err);
+            }
+            return Ok(diagnostics.is_empty());
+        }
+    }
+    Ok(true)
+}
+
+fn main() -> ExitCode {
+    match run(env::args().skip(1)) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {}\n{}", err, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
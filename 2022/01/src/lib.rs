@@ -0,0 +1,236 @@
+use aoc::{
+    parse::{self, blocks, integer, lines},
+    Error, Result, Solution,
+};
+use std::{cmp::Reverse, collections::BinaryHeap, io::BufRead};
+
+/// An elf, numbered from 1 in input order, with the calories it carries.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Elf {
+    pub id: usize,
+    pub calories: u64,
+}
+
+impl Elf {
+    /// Orders elves from most to fewest calories, ties going to the first elf.
+    fn rank_key(&self) -> (Reverse<u64>, usize) {
+        (Reverse(self.calories), self.id)
+    }
+}
+
+fn total(id: usize, calories: impl IntoIterator<Item = u64>) -> Result<Elf> {
+    calories
+        .into_iter()
+        .try_fold(0u64, u64::checked_add)
+        .map(|calories| Elf { id, calories })
+        .ok_or_else(|| Error::invalid(format!("elf {} carries too many calories", id)))
+}
+
+/// Every elf and its calories, in input order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Inventory {
+    elves: Vec<Elf>,
+}
+
+impl Inventory {
+    pub fn parse(input: &str) -> Result<Self> {
+        let elves = parse::complete(blocks(lines(integer::<u64>())), input)?
+            .into_iter()
+            .zip(1..)
+            .map(|(calories, id)| total(id, calories))
+            .collect::<Result<_>>()?;
+        Ok(Self { elves })
+    }
+
+    pub fn elves(&self) -> &[Elf] {
+        &self.elves
+    }
+
+    pub fn elf(&self, id: usize) -> Option<Elf> {
+        id.checked_sub(1)
+            .and_then(|idx| self.elves.get(idx))
+            .copied()
+    }
+
+    /// The `k` elves with the most calories, most first.
+    pub fn top(&self, k: usize) -> Vec<Elf> {
+        let mut elves = self.elves.clone();
+        elves.sort_unstable_by_key(Elf::rank_key);
+        elves.truncate(k);
+        elves
+    }
+
+    /// The position of elf `id` in the `top` order, starting at 1.
+    pub fn rank(&self, id: usize) -> Option<usize> {
+        let elf = self.elf(id)?;
+        Some(
+            1 + self
+                .elves
+                .iter()
+                .filter(|other| other.rank_key() < elf.rank_key())
+                .count(),
+        )
+    }
+}
+
+/// Every line that is neither blank nor a number of calories.
+pub fn diagnostics(input: &str) -> Vec<parse::Error> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(idx, line)| {
+            parse::complete(integer::<u64>(), line)
+                .err()
+                .map(|err| err.with_line(idx + 1))
+        })
+        .collect()
+}
+
+/// The `k` elves with the most calories, most first, reading the inventory a
+/// line at a time and only keeping the best `k` elves.
+pub fn top(reader: impl BufRead, k: usize) -> Result<Vec<Elf>> {
+    // `k` comes from the user, so the heap only grows with the elves read.
+    let mut best = BinaryHeap::new();
+    let mut keep = |elf: Elf| {
+        best.push((elf.rank_key(), elf.calories));
+        if best.len() > k {
+            best.pop();
+        }
+    };
+    let mut elf = Elf { id: 1, calories: 0 };
+    let mut empty = true;
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line.map_err(|err| Error::invalid(format!("line {}: {}", idx + 1, err)))?;
+        if line.trim().is_empty() {
+            if !empty {
+                keep(elf);
+                elf = Elf {
+                    id: elf.id + 1,
+                    calories: 0,
+                };
+                empty = true;
+            }
+            continue;
+        }
+        let calories =
+            parse::complete(integer::<u64>(), &line).map_err(|err| err.with_line(idx + 1))?;
+        elf = total(elf.id, [elf.calories, calories])?;
+        empty = false;
+    }
+    if !empty {
+        keep(elf);
+    }
+    let mut elves = best
+        .into_iter()
+        .map(|((_, id), calories)| Elf { id, calories })
+        .collect::<Vec<_>>();
+    elves.sort_unstable_by_key(Elf::rank_key);
+    Ok(elves)
+}
+
+fn one(input: &str) -> Result<u64> {
+    Ok(top(input.as_bytes(), 1)?
+        .iter()
+        .map(|elf| elf.calories)
+        .sum())
+}
+
+fn two(input: &str) -> Result<u64> {
+    Ok(top(input.as_bytes(), 3)?
+        .iter()
+        .map(|elf| elf.calories)
+        .sum())
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2022, 1).part(one).part(two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000"#;
+
+    #[test]
+    fn part_one() {
+        assert_eq!(one(INPUT), Ok(24000));
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(two(INPUT), Ok(45000));
+    }
+
+    #[test]
+    fn inventory() {
+        let inventory = Inventory::parse(INPUT).unwrap();
+        assert_eq!(inventory.elves().len(), 5);
+        assert_eq!(
+            inventory.top(2),
+            [
+                Elf {
+                    id: 4,
+                    calories: 24000
+                },
+                Elf {
+                    id: 3,
+                    calories: 11000
+                }
+            ]
+        );
+        assert_eq!(inventory.top(9).len(), 5);
+        assert_eq!(inventory.rank(4), Some(1));
+        assert_eq!(inventory.rank(2), Some(5));
+        assert_eq!(inventory.rank(6), None);
+        for k in [0, 1, 2, 3, 4, 5, 6, usize::MAX] {
+            assert_eq!(top(INPUT.as_bytes(), k), Ok(inventory.top(k)));
+        }
+        assert_eq!(
+            top("\n\n5\n\n\n5\n".as_bytes(), 2),
+            Ok(vec![Elf { id: 1, calories: 5 }, Elf { id: 2, calories: 5 }])
+        );
+        for input in ["\n\n5\n\n\n5\n", "5\r\n\r\n \r\n6\r\n", "5\n\n\n"] {
+            let inventory = Inventory::parse(input).unwrap();
+            assert_eq!(
+                top(input.as_bytes(), 3),
+                Ok(inventory.top(3)),
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(one("1000\n2000\n\nabc").is_err());
+        assert!(two("1000\n-2000").is_err());
+        assert!(two("18446744073709551615\n1").is_err());
+        match top("1\n\n2\nx".as_bytes(), 1) {
+            Err(Error::Parse(err)) => assert_eq!(err.line(), 4),
+            other => panic!("expected a parse error, found {:?}", other),
+        }
+        let diagnostics = diagnostics("1\nx\n\n2\n-3\n");
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(parse::Error::line)
+                .collect::<Vec<_>>(),
+            [2, 5]
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use aoc_2022_02::{AsOutcomes, AsShapes, Assignment, Game, Guide, Interpretation};
+use std::{env, error::Error, fs, process::ExitCode};
+
+const USAGE: &str =
+    "usage: rps [--game rps|rpsls|<shape>,<shape>,...] [--outcomes] [--analyse] <input>";
+
+fn describe(game: &Game, assignment: &Assignment) -> String {
+    let meaning = assignment
+        .meaning
+        .iter()
+        .map(|&(symbol, shape)| format!("{} = {}", symbol, game.name(shape)))
+        .collect::<Vec<_>>();
+    format!("{} ({})", assignment.score, meaning.join(", "))
+}
+
+fn report(
+    game: &Game,
+    guide: &Guide,
+    interpretation: &impl Interpretation,
+    analyse: bool,
+) -> aoc::Result<String> {
+    Ok(match analyse {
+        true => {
+            let analysis = guide.analyse(game, interpretation)?;
+            format!(
+                "best: {}\nworst: {}\n",
+                describe(game, &analysis.best),
+                describe(game, &analysis.worst)
+            )
+        }
+        false => format!("{}\n", guide.score(game, interpretation)?),
+    })
+}
+
+/// Prefixes `err` with `path`, joined like `path:line:column` for parse errors.
+fn located(path: &str, err: aoc::Error) -> String {
+    match err {
+        aoc::Error::Parse(_) => format!("{}:{}", path, err),
+        aoc::Error::Invalid(_) => format!("{}: {}", path, err),
+    }
+}
+
+fn run(mut args: impl Iterator<Item = String>) -> Result<String, Box<dyn Error>> {
+    let (mut game, mut outcomes, mut analyse, mut path) =
+        (Game::rock_paper_scissors(), false, false, None);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--game" => {
+                game = match args.next().ok_or("missing value for `--game`")?.as_str() {
+                    "rps" => Game::rock_paper_scissors(),
+                    "rpsls" => Game::rock_paper_scissors_lizard_spock(),
+                    shapes => Game::new(shapes.split(','))?,
+                }
+            }
+            "--outcomes" => outcomes = true,
+            "--analyse" => analyse = true,
+            flag if flag.starts_with("--") => return Err(format!("unknown flag `{}`", flag).into()),
+            _ if path.is_none() => path = Some(arg),
+            _ => return Err("expected a single input path".into()),
+        }
+    }
+    if outcomes && analyse {
+        return Err("`--analyse` tries the responses as shapes, not outcomes".into());
+    }
+    let path = path.ok_or("missing input path")?;
+    let input = fs::read_to_string(&path).map_err(|err| format!("{}: {}", path, err))?;
+    let guide = Guide::parse(&input).map_err(|err| located(&path, err))?;
+    match outcomes {
+        true => report(&game, &guide, &AsOutcomes, analyse),
+        false => report(&game, &guide, &AsShapes, analyse),
+    }
+    .map_err(|err| located(&path, err).into())
+}
+
+fn main() -> ExitCode {
+    match run(env::args().skip(1)) {
+        Ok(output) => {
+            print!("{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}\n{}", err, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,446 @@
+use aoc::{
+    parse::{self, lines, pair, space, take_while1, terminated, Input, PResult, Parser},
+    Error, Result, Solution,
+};
+
+/// A shape by its position in the game's cycle.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Shape(usize);
+
+impl Shape {
+    pub fn index(self) -> usize {
+        self.0
+    }
+
+    /// The score for playing this shape, 1 for the first shape and so on.
+    pub fn score(self) -> usize {
+        self.0 + 1
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub fn score(self) -> usize {
+        match self {
+            Self::Loss => 0,
+            Self::Draw => 3,
+            Self::Win => 6,
+        }
+    }
+}
+
+/// A game with an odd number of shapes in a cycle, where each shape beats the
+/// half of the other shapes just before it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    shapes: Vec<String>,
+}
+
+impl Game {
+    pub fn new(shapes: impl IntoIterator<Item = impl Into<String>>) -> Result<Self> {
+        let shapes = shapes.into_iter().map(Into::into).collect::<Vec<String>>();
+        if shapes.iter().any(|shape| shape.trim().is_empty()) {
+            return Err(Error::invalid("shapes need a name"));
+        }
+        if shapes.len() % 2 == 0 {
+            return Err(Error::invalid(format!(
+                "a cyclic game needs an odd number of shapes, not {}",
+                shapes.len()
+            )));
+        }
+        if let Some(shape) = shapes
+            .iter()
+            .enumerate()
+            .find_map(|(idx, shape)| shapes[..idx].contains(shape).then_some(shape))
+        {
+            return Err(Error::invalid(format!("{} appears twice", shape)));
+        }
+        Ok(Self { shapes })
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Self::new(["Rock", "Paper", "Scissors"]).expect("valid game")
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::new(["Rock", "Spock", "Paper", "Lizard", "Scissors"]).expect("valid game")
+    }
+
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.len()).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.shapes[shape.0]
+    }
+
+    pub fn outcome(&self, opponent: Shape, response: Shape) -> Outcome {
+        match (response.0 + self.len() - opponent.0) % self.len() {
+            0 => Outcome::Draw,
+            ahead if ahead <= self.len() / 2 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+
+    pub fn score(&self, opponent: Shape, response: Shape) -> usize {
+        response.score() + self.outcome(opponent, response).score()
+    }
+
+    /// The highest scoring response with the given outcome, if there is one.
+    pub fn respond(&self, opponent: Shape, outcome: Outcome) -> Option<Shape> {
+        self.shapes()
+            .filter(|&response| self.outcome(opponent, response) == outcome)
+            .last()
+    }
+}
+
+/// The offset of the single letter `symbol` from `first`, if below `count`.
+fn letter(symbol: &str, first: u8, count: usize) -> Option<usize> {
+    match symbol.as_bytes() {
+        &[char] => usize::from(char)
+            .checked_sub(usize::from(first))
+            .filter(|&idx| idx < count),
+        _ => None,
+    }
+}
+
+fn expected(first: u8, count: usize) -> String {
+    match count {
+        1 => format!("expected {}", char::from(first)),
+        _ => format!(
+            "expected one of {}-{}",
+            char::from(first),
+            char::from(first + count as u8 - 1)
+        ),
+    }
+}
+
+/// What the columns of a strategy guide mean.
+pub trait Interpretation {
+    /// The shape the opponent plays, `A` for the first shape, `B` for the
+    /// second and so on unless overridden.
+    fn opponent(&self, game: &Game, symbol: &str) -> Result<Shape, String> {
+        if game.len() > 26 {
+            return Err("too many shapes to name with letters".to_string());
+        }
+        letter(symbol, b'A', game.len())
+            .map(Shape)
+            .ok_or_else(|| expected(b'A', game.len()))
+    }
+
+    /// The shape to play in response.
+    fn response(&self, game: &Game, opponent: Shape, symbol: &str) -> Result<Shape, String>;
+}
+
+/// The second column is the shape to play, with the shapes named by the last
+/// letters of the alphabet: `X`, `Y` and `Z` for three shapes.
+pub struct AsShapes;
+
+impl Interpretation for AsShapes {
+    fn response(&self, game: &Game, _: Shape, symbol: &str) -> Result<Shape, String> {
+        if game.len() > 26 {
+            return Err("too many shapes to name with letters".to_string());
+        }
+        let first = b'Z' + 1 - game.len() as u8;
+        letter(symbol, first, game.len())
+            .map(Shape)
+            .ok_or_else(|| expected(first, game.len()))
+    }
+}
+
+/// The second column is how the round needs to end: `X` to lose, `Y` to draw
+/// and `Z` to win, playing the highest scoring shape that does so.
+pub struct AsOutcomes;
+
+impl Interpretation for AsOutcomes {
+    fn response(&self, game: &Game, opponent: Shape, symbol: &str) -> Result<Shape, String> {
+        let outcome = match symbol {
+            "X" => Outcome::Loss,
+            "Y" => Outcome::Draw,
+            "Z" => Outcome::Win,
+            _ => return Err(expected(b'X', 3)),
+        };
+        game.respond(opponent, outcome).ok_or_else(|| {
+            let verb = match outcome {
+                Outcome::Loss => "loses to",
+                Outcome::Draw => "draws with",
+                Outcome::Win => "beats",
+            };
+            format!("no shape {} {}", verb, game.name(opponent))
+        })
+    }
+}
+
+/// The meaning of each response symbol and the resulting score.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Assignment<'a> {
+    pub score: usize,
+    pub meaning: Vec<(&'a str, Shape)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Analysis<'a> {
+    pub best: Assignment<'a>,
+    pub worst: Assignment<'a>,
+}
+
+/// A strategy guide as written, before deciding what its columns mean.
+pub struct Guide<'a> {
+    source: &'a str,
+    rounds: Vec<(&'a str, &'a str)>,
+}
+
+fn round<'a>(input: Input<'a>) -> PResult<'a, (&'a str, &'a str)> {
+    let symbol = || take_while1("a symbol", |x| !x.is_whitespace());
+    pair(terminated(symbol(), space()), symbol()).parse(input)
+}
+
+impl<'a> Guide<'a> {
+    pub fn parse(source: &'a str) -> Result<Self> {
+        Ok(Self {
+            source,
+            rounds: parse::complete(lines(round), source)?,
+        })
+    }
+
+    /// The opponent's shape in each round, with the unresolved response.
+    fn opponents(
+        &self,
+        game: &Game,
+        interpretation: &impl Interpretation,
+    ) -> Result<Vec<(Shape, &'a str)>> {
+        self.rounds
+            .iter()
+            .map(|&(opponent, response)| {
+                interpretation
+                    .opponent(game, opponent)
+                    .map(|opponent| (opponent, response))
+                    .map_err(|err| parse::Error::at(self.source, opponent, err).into())
+            })
+            .collect()
+    }
+
+    /// The total score when following the guide as `interpretation` reads it.
+    pub fn score(&self, game: &Game, interpretation: &impl Interpretation) -> Result<usize> {
+        self.opponents(game, interpretation)?
+            .into_iter()
+            .map(|(opponent, symbol)| {
+                interpretation
+                    .response(game, opponent, symbol)
+                    .map(|response| game.score(opponent, response))
+                    .map_err(|err| parse::Error::at(self.source, symbol, err).into())
+            })
+            .sum()
+    }
+
+    /// The highest and lowest total score over every way to read the response
+    /// symbols as distinct shapes, with `interpretation` reading the opponent.
+    pub fn analyse(
+        &self,
+        game: &Game,
+        interpretation: &impl Interpretation,
+    ) -> Result<Analysis<'a>> {
+        let rounds = self.opponents(game, interpretation)?;
+        let mut symbols = Vec::<&str>::new();
+        for &(_, symbol) in &rounds {
+            if !symbols.contains(&symbol) {
+                symbols.push(symbol);
+            }
+        }
+        if symbols.len() > game.len() {
+            return Err(Error::invalid(format!(
+                "{} response symbols for {} shapes",
+                symbols.len(),
+                game.len()
+            )));
+        }
+        if game.len() > 20 {
+            return Err(Error::invalid(format!(
+                "too many shapes to analyse: {}",
+                game.len()
+            )));
+        }
+
+        // The score each symbol contributes when read as each shape.
+        let mut scores = vec![vec![0; game.len()]; symbols.len()];
+        for (opponent, symbol) in rounds {
+            let idx = symbols.iter().position(|&x| x == symbol).expect("symbol");
+            for response in game.shapes() {
+                scores[idx][response.0] += game.score(opponent, response);
+            }
+        }
+        let assignment = |better: fn(usize, usize) -> bool| {
+            let (score, shapes) = assign(&scores, game.len(), better);
+            Assignment {
+                score,
+                meaning: symbols
+                    .iter()
+                    .copied()
+                    .zip(shapes.into_iter().map(Shape))
+                    .collect(),
+            }
+        };
+        Ok(Analysis {
+            best: assignment(|a, b| a > b),
+            worst: assignment(|a, b| a < b),
+        })
+    }
+}
+
+/// Assigns each symbol a distinct shape for the `better` total score, building
+/// up assignments by the set of shapes used so far.
+fn assign(
+    scores: &[Vec<usize>],
+    shapes: usize,
+    better: fn(usize, usize) -> bool,
+) -> (usize, Vec<usize>) {
+    // The best score for each set of shapes given to the first symbols, with
+    // the shape given to the last of them.
+    let mut best = vec![None::<(usize, usize)>; 1 << shapes];
+    best[0] = Some((0, 0));
+    for used in 0..best.len() {
+        let Some((score, _)) = best[used] else {
+            continue;
+        };
+        let Some(symbol) = scores.get(used.count_ones() as usize) else {
+            continue;
+        };
+        for shape in (0..shapes).filter(|shape| used >> shape & 1 == 0) {
+            let (next, score) = (used | 1 << shape, score + symbol[shape]);
+            if best[next].is_none_or(|(other, _)| better(score, other)) {
+                best[next] = Some((score, shape));
+            }
+        }
+    }
+
+    let (mut used, score) = (0..best.len())
+        .filter(|used| used.count_ones() as usize == scores.len())
+        .filter_map(|used| Some((used, best[used]?.0)))
+        .reduce(|a, b| if better(b.1, a.1) { b } else { a })
+        .expect("at least as many shapes as symbols");
+    let mut assignment = vec![0; scores.len()];
+    for symbol in (0..scores.len()).rev() {
+        let shape = best[used].expect("reachable").1;
+        assignment[symbol] = shape;
+        used &= !(1 << shape);
+    }
+    (score, assignment)
+}
+
+fn one(input: &str) -> Result<usize> {
+    Guide::parse(input)?.score(&Game::rock_paper_scissors(), &AsShapes)
+}
+
+fn two(input: &str) -> Result<usize> {
+    Guide::parse(input)?.score(&Game::rock_paper_scissors(), &AsOutcomes)
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2022, 2).part(one).part(two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"A Y
+B X
+C Z"#;
+
+    #[test]
+    fn part_one() {
+        assert_eq!(one(INPUT), Ok(15));
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(two(INPUT), Ok(12));
+    }
+
+    #[test]
+    fn games() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let shape = |name| game.shapes().find(|&x| game.name(x) == name).unwrap();
+        for (winner, loser) in [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ] {
+            assert_eq!(game.outcome(shape(loser), shape(winner)), Outcome::Win);
+            assert_eq!(game.outcome(shape(winner), shape(loser)), Outcome::Loss);
+        }
+        assert_eq!(
+            game.respond(shape("Rock"), Outcome::Win),
+            Some(shape("Paper"))
+        );
+        assert_eq!(
+            Guide::parse("A V\nE Z").unwrap().score(&game, &AsShapes),
+            Ok(1 + 3 + 5 + 3)
+        );
+        assert!(Game::new(["Rock", "Paper"]).is_err());
+        assert!(Game::new(["Rock", "Paper", "Rock"]).is_err());
+        assert!(Game::new(["Rock", "Paper", ""]).is_err());
+        assert!(Game::new(["Rock", " ", "Scissors"]).is_err());
+        assert_eq!(
+            Guide::parse("A X")
+                .unwrap()
+                .score(&Game::new(["Rock"]).unwrap(), &AsOutcomes),
+            Err(parse::Error::new("A X", 2, "no shape loses to Rock").into())
+        );
+    }
+
+    #[test]
+    fn analysis() {
+        let game = Game::rock_paper_scissors();
+        let analysis = Guide::parse(INPUT)
+            .unwrap()
+            .analyse(&game, &AsShapes)
+            .unwrap();
+        assert_eq!(analysis.best.score, 24);
+        assert_eq!(analysis.worst.score, 6);
+        let names = |assignment: &Assignment| {
+            assignment
+                .meaning
+                .iter()
+                .map(|&(symbol, shape)| format!("{}={}", symbol, game.name(shape)))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&analysis.best), ["Y=Paper", "X=Scissors", "Z=Rock"]);
+        assert_eq!(names(&analysis.worst), ["Y=Scissors", "X=Rock", "Z=Paper"]);
+        assert!(Guide::parse("A X\nA Y\nA Z\nA W")
+            .unwrap()
+            .analyse(&game, &AsShapes)
+            .is_err());
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(one("A Y\nB W").is_err());
+        assert!(two("A\nB X").is_err());
+        match one("A Y\nD X") {
+            Err(Error::Parse(err)) => assert_eq!((err.line(), err.column()), (2, 1)),
+            other => panic!("expected a parse error, found {:?}", other),
+        }
+    }
+}
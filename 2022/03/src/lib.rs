@@ -0,0 +1,100 @@
+#![feature(iter_array_chunks)]
+
+use aoc::{
+    parse::{self, lines, take_while1},
+    Error, Result, Solution,
+};
+use std::collections::HashSet;
+
+fn parse(input: &str) -> Result<Vec<&str>> {
+    let rucksacks = parse::complete(
+        lines(take_while1("an item (a-z, A-Z)", |x| {
+            x.is_ascii_alphabetic()
+        })),
+        input,
+    )?;
+    match rucksacks.iter().find(|rucksack| rucksack.len() % 2 != 0) {
+        Some(rucksack) => {
+            Err(parse::Error::at(input, rucksack, "expected an even number of items").into())
+        }
+        None => Ok(rucksacks),
+    }
+}
+
+fn one(input: &str) -> Result<u32> {
+    Ok(parse(input)?
+        .into_iter()
+        .map(|rucksack| rucksack.split_at(rucksack.len() / 2))
+        .flat_map(|(one, two)| {
+            let set = one.chars().collect::<HashSet<_>>();
+            two.chars().find(|item| set.contains(item))
+        })
+        .map(priority)
+        .sum())
+}
+
+fn two(input: &str) -> Result<u32> {
+    let rucksacks = parse(input)?;
+    if rucksacks.len() % 3 != 0 {
+        return Err(Error::invalid(format!(
+            "{} rucksacks can't be split in groups of three",
+            rucksacks.len()
+        )));
+    }
+    Ok(rucksacks
+        .into_iter()
+        .array_chunks::<3>()
+        .flat_map(|group| {
+            group
+                .into_iter()
+                .map(str::chars)
+                .map(HashSet::<char>::from_iter)
+                .reduce(|mut set, items| {
+                    set.retain(|item| items.contains(item));
+                    set
+                })
+        })
+        .flat_map(IntoIterator::into_iter)
+        .map(priority)
+        .sum())
+}
+
+fn priority(input: char) -> u32 {
+    match input {
+        'a'..='z' => input as u32 - 96,
+        _ => input as u32 - 38,
+    }
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2022, 3).part(one).part(two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw"#;
+
+    #[test]
+    fn part_one() {
+        assert_eq!(one(INPUT), Ok(157));
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(two(INPUT), Ok(70));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(one("vJrwpWtwJgWrhcsFMMfFFhF").is_err());
+        assert!(one("vJrwpWtwJgWrh-sFMMfFFhFp").is_err());
+        assert!(two("vJrwpWtwJgWrhcsFMMfFFhFp").is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use aoc::{
+    parse::{self, integer, lines, pair, tag, terminated, Input, PResult, Parser},
+    Result, Solution,
+};
+use std::ops::RangeInclusive;
+
+fn range(input: Input) -> PResult<RangeInclusive<usize>> {
+    let ((start, end), input) = pair(terminated(integer(), tag("-")), integer()).parse(input)?;
+    Ok((start..=end, input))
+}
+
+fn parse(input: &str) -> Result<Vec<[RangeInclusive<usize>; 2]>> {
+    let pairs = parse::complete(lines(pair(terminated(range, tag(",")), range)), input)?;
+    Ok(pairs.into_iter().map(|(one, two)| [one, two]).collect())
+}
+
+fn one(input: &str) -> Result<usize> {
+    Ok(parse(input)?
+        .into_iter()
+        .filter(|[one, two]| {
+            one.start() >= two.start() && one.end() <= two.end()
+                || two.start() >= one.start() && two.end() <= one.end()
+        })
+        .count())
+}
+
+fn two(input: &str) -> Result<usize> {
+    Ok(parse(input)?
+        .into_iter()
+        .filter(|[one, two]| one.start().max(two.start()) <= one.end().min(two.end()))
+        .count())
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2022, 4).part(one).part(two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8"#;
+
+    #[test]
+    fn part_one() {
+        assert_eq!(one(INPUT), Ok(2));
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(two(INPUT), Ok(4));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(one("2-4,6-8\n2-3;4-5").is_err());
+        assert!(two("2-4,6").is_err());
+    }
+}
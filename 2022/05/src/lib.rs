@@ -0,0 +1,207 @@
+#![feature(iter_array_chunks)]
+
+use aoc::{
+    parse::{self, integer, lines, map_res, paragraph, preceded, tag, Input, PResult, Parser},
+    Error, Result, Solution,
+};
+use std::iter;
+
+struct Crates {
+    stacks: Vec<Vec<char>>,
+}
+
+impl Crates {
+    /// Parses the `crates` drawing, a subslice of the puzzle `source`.
+    fn parse(source: &str, crates: &str) -> Result<Self> {
+        let mut input = crates.lines().rev();
+        let positions = input.next().map_or(0, |labels| (labels.len() + 1) / 4);
+        let mut stacks = vec![Vec::new(); positions];
+        for line in input {
+            for (pos, item) in line
+                .chars()
+                .chain(iter::once(' '))
+                .array_chunks::<4>()
+                .map(|[_, x, _, _]| x)
+                .enumerate()
+                .filter(|(_, item)| *item != ' ')
+            {
+                stacks
+                    .get_mut(pos)
+                    .ok_or_else(|| {
+                        parse::Error::at(
+                            source,
+                            line,
+                            format!("expected at most {} stacks", positions),
+                        )
+                    })?
+                    .push(item);
+            }
+        }
+        Ok(Self { stacks })
+    }
+    fn stack(&mut self, index: usize) -> Result<&mut Vec<char>> {
+        let len = self.stacks.len();
+        self.stacks
+            .get_mut(index)
+            .ok_or_else(|| Error::invalid(format!("stack {} out of {}", index + 1, len)))
+    }
+    /// Lifts the top `amount` crates off the source stack, bottom first.
+    fn lift(&mut self, instruction: &Instruction) -> Result<Vec<char>> {
+        self.stack(instruction.destination)?;
+        let source = self.stack(instruction.source)?;
+        let split_index = source
+            .len()
+            .checked_sub(instruction.amount)
+            .ok_or_else(|| {
+                Error::invalid(format!(
+                    "can't move {} crates from stack {} holding {}",
+                    instruction.amount,
+                    instruction.source + 1,
+                    source.len()
+                ))
+            })?;
+        Ok(source.split_off(split_index))
+    }
+    fn apply(&mut self, instruction: Instruction) -> Result<()> {
+        let mut lifted = self.lift(&instruction)?;
+        // Moving one crate at a time reverses them, unless they go straight back.
+        if instruction.source != instruction.destination {
+            lifted.reverse();
+        }
+        self.stack(instruction.destination)?.append(&mut lifted);
+        Ok(())
+    }
+    fn top(&self) -> String {
+        self.stacks
+            .iter()
+            .filter_map(|stack| stack.last())
+            .collect()
+    }
+    fn apply_9001(&mut self, instruction: Instruction) -> Result<()> {
+        let mut lifted = self.lift(&instruction)?;
+        self.stack(instruction.destination)?.append(&mut lifted);
+        Ok(())
+    }
+}
+
+struct Instruction {
+    amount: usize,
+    source: usize,
+    destination: usize,
+}
+
+fn stack(input: Input) -> PResult<usize> {
+    map_res(integer(), |stack: usize| {
+        stack.checked_sub(1).ok_or("stacks are numbered from 1")
+    })
+    .parse(input)
+}
+
+fn instruction(input: Input) -> PResult<Instruction> {
+    let (amount, input) = preceded(tag("move "), integer()).parse(input)?;
+    let (source, input) = preceded(tag(" from "), stack).parse(input)?;
+    let (destination, input) = preceded(tag(" to "), stack).parse(input)?;
+    Ok((
+        Instruction {
+            amount,
+            source,
+            destination,
+        },
+        input,
+    ))
+}
+
+fn procedure<'a>(input: Input<'a>) -> PResult<'a, (&'a str, Vec<Instruction>)> {
+    let (crates, input) = paragraph().parse(input)?;
+    let (instructions, input) = lines(instruction).parse(input)?;
+    Ok(((crates, instructions), input))
+}
+
+fn parse(input: &str) -> Result<(Crates, Vec<Instruction>)> {
+    let (crates, instructions) = parse::complete(procedure, input)?;
+    Ok((Crates::parse(input, crates)?, instructions))
+}
+
+fn one(input: &str) -> Result<String> {
+    let (mut crates, instructions) = parse(input)?;
+    for instruction in instructions {
+        crates.apply(instruction)?;
+    }
+    Ok(crates.top())
+}
+
+fn two(input: &str) -> Result<String> {
+    let (mut crates, instructions) = parse(input)?;
+    for instruction in instructions {
+        crates.apply_9001(instruction)?;
+    }
+    Ok(crates.top())
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2022, 5).part(one).part(two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2"#;
+
+    #[test]
+    fn part_one() {
+        assert_eq!(one(INPUT), Ok("CMZ".to_string()));
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(two(INPUT), Ok("MCD".to_string()));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(one(&INPUT.replace("from 2 to 1", "from 2 to 4")).is_err());
+        assert!(two(&INPUT.replace("move 3", "move 4")).is_err());
+        let too_many = INPUT.replace("move 3", "move 4");
+        assert_eq!(
+            one(&too_many).err().map(|err| err.to_string()),
+            Some("can't move 4 crates from stack 1 holding 3".to_string())
+        );
+        assert_eq!(
+            one(&too_many).err().map(|err| err.to_string()),
+            two(&too_many).err().map(|err| err.to_string())
+        );
+        assert!(one(&INPUT.replace("[P]", "[P] [Q]")).is_err());
+        assert!(one(&INPUT.replace("move 1 from 2", "move one from 2")).is_err());
+    }
+
+    #[test]
+    fn same_stack() {
+        for apply in [Crates::apply, Crates::apply_9001] {
+            let mut crates = Crates {
+                stacks: vec![vec!['A', 'B', 'C']],
+            };
+            let instruction = Instruction {
+                amount: 2,
+                source: 0,
+                destination: 0,
+            };
+            assert_eq!(apply(&mut crates, instruction), Ok(()));
+            assert_eq!(crates.stacks, [['A', 'B', 'C']]);
+        }
+    }
+
+    #[test]
+    fn blank_lines() {
+        assert_eq!(one(&INPUT.replace('\n', "\r\n")), Ok("CMZ".to_string()));
+        assert_eq!(two(&INPUT.replace("\n\n", "\n\n\n")), Ok("MCD".to_string()));
+    }
+}
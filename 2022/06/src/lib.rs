@@ -0,0 +1,53 @@
+use aoc::{Error, Result, Solution};
+use std::collections::HashSet;
+
+fn find_run_position(input: &str, len: usize) -> Result<usize> {
+    input
+        .trim_end()
+        .as_bytes()
+        .windows(len)
+        .position(|items| items.iter().collect::<HashSet<_>>().len() == len)
+        .map(|position| position + len)
+        .ok_or_else(|| Error::invalid(format!("no run of {} distinct characters", len)))
+}
+
+fn one(input: &str) -> Result<usize> {
+    find_run_position(input, 4)
+}
+
+fn two(input: &str) -> Result<usize> {
+    find_run_position(input, 14)
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2022, 6).part(one).part(two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        assert_eq!(one("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), Ok(7));
+        assert_eq!(one("bvwbjplbgvbhsrlpgdmjqwftvncz"), Ok(5));
+        assert_eq!(one("nppdvjthqldpwncqszvftbrmjlhg"), Ok(6));
+        assert_eq!(one("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), Ok(10));
+        assert_eq!(one("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), Ok(11));
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(two("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), Ok(19));
+        assert_eq!(two("bvwbjplbgvbhsrlpgdmjqwftvncz"), Ok(23));
+        assert_eq!(two("nppdvjthqldpwncqszvftbrmjlhg"), Ok(23));
+        assert_eq!(two("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), Ok(29));
+        assert_eq!(two("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), Ok(26));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(one("mjqjmjqj\n").is_err());
+        assert!(two("").is_err());
+    }
+}
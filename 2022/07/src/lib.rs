@@ -0,0 +1,134 @@
+use aoc::{
+    parse::{
+        self, integer, lines, map, opt, pair, preceded, space, tag, terminated, word, Input,
+        PResult, Parser,
+    },
+    Error, Result, Solution,
+};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+enum Line<'a> {
+    Cd(&'a str),
+    Ls,
+    Dir,
+    File(usize),
+}
+
+fn line<'a>(input: Input<'a>) -> PResult<'a, Line<'a>> {
+    if let (Some(_), input) = opt(tag("$ ")).parse(input)? {
+        return match opt(tag("ls")).parse(input)? {
+            (Some(_), input) => Ok((Line::Ls, input)),
+            (None, input) => map(preceded(tag("cd "), word()), Line::Cd).parse(input),
+        };
+    }
+    match opt(tag("dir ")).parse(input)? {
+        (Some(_), input) => map(word(), |_| Line::Dir).parse(input),
+        (None, input) => map(pair(terminated(integer(), space()), word()), |(size, _)| {
+            Line::File(size)
+        })
+        .parse(input),
+    }
+}
+
+fn parse(input: &str) -> Result<HashMap<PathBuf, usize>> {
+    let mut cwd = PathBuf::default();
+    let mut sizes: HashMap<PathBuf, usize> = HashMap::default();
+    for line in parse::complete(lines(line), input)? {
+        match line {
+            Line::Cd("/") => {
+                cwd = PathBuf::from("/");
+            }
+            Line::Cd("..") => {
+                cwd.pop();
+            }
+            Line::Cd(path) => {
+                cwd.push(path);
+            }
+            Line::Ls | Line::Dir => {}
+            Line::File(file_size) => {
+                for ancestor in cwd.ancestors() {
+                    sizes
+                        .entry(ancestor.to_path_buf())
+                        .and_modify(|size| {
+                            *size += file_size;
+                        })
+                        .or_insert_with(|| file_size);
+                }
+            }
+        }
+    }
+    Ok(sizes)
+}
+
+fn one(input: &str) -> Result<usize> {
+    Ok(parse(input)?.values().filter(|&&x| x <= 100_000).sum())
+}
+
+fn two(input: &str) -> Result<usize> {
+    let sizes = parse(input)?;
+    let used = sizes.get(Path::new("/")).copied().unwrap_or_default();
+    let unused = 70_000_000usize
+        .checked_sub(used)
+        .ok_or_else(|| Error::invalid(format!("{} used exceeds the disk size", used)))?;
+    let needed = 30_000_000usize.saturating_sub(unused);
+    Ok(sizes
+        .values()
+        .filter(|&&size| size >= needed)
+        .min()
+        .copied()
+        .unwrap_or_default())
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2022, 7).part(one).part(two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+"#;
+
+    #[test]
+    fn part_one() {
+        assert_eq!(one(INPUT), Ok(95437));
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(two(INPUT), Ok(24933642));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(one("$ cd /\n$ rm a").is_err());
+        assert!(one("$ cd /\n$ ls\nfile a").is_err());
+        assert!(two("$ cd /\n$ ls\n80000000 a").is_err());
+    }
+}
@@ -0,0 +1,76 @@
+use aoc::{parse, Result, Solution};
+
+fn first_last_digit<I: DoubleEndedIterator<Item = u32>>(mut digits: I) -> Option<u32> {
+    digits
+        .next()
+        .map(|first| 10 * first + digits.next_back().unwrap_or(first))
+}
+
+/// Sums the calibration values of all lines, which must each contain a digit.
+fn calibrate<'a, I: DoubleEndedIterator<Item = u32>>(
+    input: &'a str,
+    digits: impl Fn(&'a str) -> I,
+) -> Result<u32> {
+    input.lines().try_fold(0, |sum, line| {
+        first_last_digit(digits(line))
+            .map(|value| sum + value)
+            .ok_or_else(|| parse::Error::at(input, line, "expected a digit").into())
+    })
+}
+
+fn part_1(input: &str) -> Result<u32> {
+    calibrate(input, |line| {
+        line.chars().filter_map(|char| char.to_digit(10))
+    })
+}
+
+fn part_2(input: &str) -> Result<u32> {
+    const DIGITS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    calibrate(input, |line| {
+        line.char_indices().filter_map(move |(idx, char)| {
+            char.to_digit(10).or_else(|| {
+                DIGITS.into_iter().enumerate().find_map(|(value, digit)| {
+                    line[idx..].starts_with(digit).then_some(value as u32 + 1)
+                })
+            })
+        })
+    })
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2023, 1).part(part_1).part(part_2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_1() {
+        const INPUT: &str = r#"1abc2
+        pqr3stu8vwx
+        a1b2c3d4e5f
+        treb7uchet"#;
+        assert_eq!(part_1(INPUT), Ok(142));
+    }
+
+    #[test]
+    fn example_2() {
+        const INPUT: &str = r#"two1nine
+        eightwothree
+        abcone2threexyz
+        xtwone3four
+        4nineeightseven2
+        zoneight234
+        7pqrstsixteen"#;
+        assert_eq!(part_2(INPUT), Ok(281));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_1("1abc2\npqrstuvwx").is_err());
+        assert!(part_2("1abc2\npqrstuvwx").is_err());
+    }
+}
@@ -1 +1 @@
-
+pub mod day1;
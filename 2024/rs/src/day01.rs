@@ -0,0 +1,69 @@
+use aoc::{
+    parse::{self, integer, lines, pair, space, terminated},
+    Result, Solution,
+};
+use std::collections::HashMap;
+
+fn parse(input: &str) -> Result<Vec<(u32, u32)>> {
+    Ok(parse::complete(
+        lines(pair(terminated(integer(), space()), integer())),
+        input,
+    )?)
+}
+
+fn part_1(input: &str) -> Result<u32> {
+    let (mut left, mut right): (Vec<_>, Vec<_>) = parse(input)?.into_iter().unzip();
+    left.sort_unstable();
+    right.sort_unstable();
+    Ok(left
+        .into_iter()
+        .zip(right)
+        .map(|(left, right)| left.abs_diff(right))
+        .sum())
+}
+
+fn part_2(input: &str) -> Result<u32> {
+    let mut map = HashMap::<_, u32>::default();
+    Ok(parse(input)?
+        .into_iter()
+        .map(|(left, right)| {
+            *map.entry(right).or_insert(0) += 1;
+            left
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|id| id * map.get(&id).unwrap_or(&0))
+        .sum())
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2024, 1).part(part_1).part(part_2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"3   4
+4   3
+2   5
+1   3
+3   9
+3   3"#;
+
+    #[test]
+    fn example_1() {
+        assert_eq!(part_1(INPUT), Ok(11));
+    }
+
+    #[test]
+    fn example_2() {
+        assert_eq!(part_2(INPUT), Ok(31));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_1("3   4\n4   x").is_err());
+        assert!(part_2("3   4\n4").is_err());
+    }
+}
@@ -0,0 +1,90 @@
+use aoc::{
+    parse::{self, integer, lines, separated, space},
+    Result, Solution,
+};
+
+fn parse(input: &str) -> Result<Vec<Report>> {
+    let reports = parse::complete(lines(separated(integer(), space())), input)?;
+    Ok(reports.into_iter().map(Report).collect())
+}
+
+struct Report(Vec<usize>);
+
+impl Report {
+    fn all_increasing(&self) -> bool {
+        self.0.is_sorted_by(|a, b| b > a)
+    }
+
+    fn all_decreasing(&self) -> bool {
+        self.0.is_sorted_by(|a, b| b < a)
+    }
+
+    fn level_diff(&self) -> bool {
+        self.0
+            .iter()
+            .zip(self.0.iter().skip(1))
+            .all(|(a, b)| a.abs_diff(*b) <= 3)
+    }
+
+    fn is_safe(&self) -> bool {
+        (self.all_decreasing() || self.all_increasing()) && self.level_diff()
+    }
+
+    fn without(&self, idx: usize) -> Self {
+        let mut levels = self.0.clone();
+        levels.remove(idx);
+        Self(levels)
+    }
+}
+
+fn part_1(input: &str) -> Result<usize> {
+    Ok(parse(input)?
+        .iter()
+        .filter(|report| report.is_safe())
+        .count())
+}
+
+fn part_2(input: &str) -> Result<usize> {
+    Ok(parse(input)?
+        .iter()
+        .filter(|report| {
+            report.is_safe()
+                || (0..report.0.len())
+                    .map(|idx| report.without(idx))
+                    .any(|report| report.is_safe())
+        })
+        .count())
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2024, 2).part(part_1).part(part_2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+"#;
+
+    #[test]
+    fn example_1() {
+        assert_eq!(part_1(INPUT), Ok(2));
+    }
+
+    #[test]
+    fn example_2() {
+        assert_eq!(part_2(INPUT), Ok(4));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_1("7 6 4 2 1\n1 2 -7 8 9").is_err());
+        assert!(part_2("7 6 4 2 1\n\n1 2 7 8 9").is_err());
+    }
+}
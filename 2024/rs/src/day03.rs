@@ -0,0 +1,86 @@
+use aoc::{Result, Solution};
+use logos::{Lexer, Logos};
+use regex::Regex;
+
+const MUL: &str = r"mul\(([0-9]{1,3}),([0-9]{1,3})\)";
+
+fn part_1(input: &str) -> Result<usize> {
+    let mul = Regex::new(MUL).unwrap();
+    Ok(mul
+        .captures_iter(input)
+        .map(|c| c.extract())
+        .map(|(_, [a, b])| a.parse::<usize>().unwrap() * b.parse::<usize>().unwrap())
+        .sum())
+}
+
+fn mul(lex: &mut Lexer<Instruction>) -> (usize, usize) {
+    let mul = Regex::new(MUL).unwrap();
+    mul.captures(lex.slice())
+        .map(|c| c.extract())
+        .map(|(_, [a, b])| (a.parse::<usize>().unwrap(), b.parse::<usize>().unwrap()))
+        .unwrap()
+}
+
+#[derive(Logos, PartialEq)]
+#[logos(skip r"[ \t\n\f]+")] // Ignore this regex pattern between tokens
+enum Instruction {
+    #[token("do()")]
+    Start,
+    #[token("don't()")]
+    Stop,
+    #[regex(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)", mul)]
+    Mul((usize, usize)),
+}
+
+struct State {
+    enabled: bool,
+    sum: usize,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sum: 0,
+        }
+    }
+}
+
+fn part_2(input: &str) -> Result<usize> {
+    Ok(Instruction::lexer(input)
+        .fold(State::default(), |mut state, result| {
+            match result {
+                Ok(Instruction::Start) => state.enabled = true,
+                Ok(Instruction::Stop) => state.enabled = false,
+                Ok(Instruction::Mul((a, b))) if state.enabled => state.sum += a * b,
+                _ => {}
+            }
+            state
+        })
+        .sum)
+}
+
+pub fn solution() -> Solution {
+    Solution::new(2024, 3).part(part_1).part(part_2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_1() {
+        assert_eq!(
+            part_1(r#"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"#),
+            Ok(161)
+        );
+    }
+
+    #[test]
+    fn example_2() {
+        assert_eq!(
+            part_2(r"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"),
+            Ok(48)
+        );
+    }
+}
@@ -1 +1,3 @@
-
+pub mod day01;
+pub mod day02;
+pub mod day03;
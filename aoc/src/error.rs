@@ -0,0 +1,36 @@
+use crate::parse;
+use std::{error, fmt};
+
+/// Errors shared by all solutions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Malformed input, located in the source.
+    Parse(parse::Error),
+    /// Well-formed input that can't be solved.
+    Invalid(String),
+}
+
+impl Error {
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Self::Invalid(message.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parse(err) => err.fmt(f),
+            Self::Invalid(message) => f.write_str(message),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<parse::Error> for Error {
+    fn from(err: parse::Error) -> Self {
+        Self::Parse(err)
+    }
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
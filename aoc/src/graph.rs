@@ -0,0 +1,502 @@
+use std::{
+    borrow::Borrow,
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    error::Error,
+    fmt,
+    hash::Hash,
+};
+
+/// Interned node identifier, only meaningful for the graph that created it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Directed graph with interned nodes of type `N` and edge labels of type `E`.
+#[derive(Clone, Debug)]
+pub struct Graph<N, E = ()> {
+    nodes: Vec<N>,
+    ids: HashMap<N, NodeId>,
+    edges: Vec<Vec<(NodeId, E)>>,
+}
+
+impl<N, E> Default for Graph<N, E> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            ids: HashMap::new(),
+            edges: Vec::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash, E> Graph<N, E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intern(&mut self, node: N) -> NodeId {
+        if let Some(&id) = self.ids.get(&node) {
+            return id;
+        }
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(node.clone());
+        self.ids.insert(node, id);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    pub fn id<Q>(&self, node: &Q) -> Option<NodeId>
+    where
+        N: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.ids.get(node).copied()
+    }
+
+    pub fn add_edge(&mut self, from: N, to: N, edge: E) -> (NodeId, NodeId) {
+        let (from, to) = (self.intern(from), self.intern(to));
+        self.connect(from, to, edge);
+        (from, to)
+    }
+
+    /// Returns a graph with every edge flipped.
+    pub fn reversed(&self) -> Self
+    where
+        E: Clone,
+    {
+        let mut edges = vec![Vec::new(); self.len()];
+        self.ids().for_each(|from| {
+            self.edges(from)
+                .iter()
+                .for_each(|(to, edge)| edges[to.0].push((from, edge.clone())))
+        });
+        Self {
+            nodes: self.nodes.clone(),
+            ids: self.ids.clone(),
+            edges,
+        }
+    }
+}
+
+impl<N, E> Graph<N, E> {
+    pub fn connect(&mut self, from: NodeId, to: NodeId, edge: E) {
+        self.edges[from.0].push((to, edge));
+    }
+
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id.0]
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.len()).map(NodeId)
+    }
+
+    pub fn edges(&self, id: NodeId) -> &[(NodeId, E)] {
+        &self.edges[id.0]
+    }
+
+    pub fn neighbours(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges(id).iter().map(|&(to, _)| to)
+    }
+
+    /// Breadth-first traversal yielding every reachable node with its depth.
+    pub fn bfs(&self, start: NodeId) -> Bfs<'_, N, E> {
+        let mut seen = vec![false; self.len()];
+        seen[start.0] = true;
+        Bfs {
+            graph: self,
+            queue: VecDeque::from([(start, 0)]),
+            seen,
+        }
+    }
+
+    /// Depth-first traversal yielding every reachable node in pre-order.
+    pub fn dfs(&self, start: NodeId) -> Dfs<'_, N, E> {
+        Dfs {
+            graph: self,
+            stack: vec![start],
+            seen: vec![false; self.len()],
+        }
+    }
+
+    /// Shortest distances from `start` to every node.
+    pub fn dijkstra(&self, start: NodeId, cost: impl Fn(&E) -> u64) -> Vec<Option<u64>> {
+        let mut distances = vec![None; self.len()];
+        let mut heap = BinaryHeap::from([Reverse((0, start))]);
+        while let Some(Reverse((distance, node))) = heap.pop() {
+            if distances[node.0].is_some() {
+                continue;
+            }
+            distances[node.0] = Some(distance);
+            self.edges(node)
+                .iter()
+                .filter(|(to, _)| distances[to.0].is_none())
+                .for_each(|(to, edge)| heap.push(Reverse((distance + cost(edge), *to))));
+        }
+        distances
+    }
+
+    /// Cheapest path from `start` to `goal`, guided by an admissible `heuristic`.
+    pub fn astar(
+        &self,
+        start: NodeId,
+        goal: NodeId,
+        cost: impl Fn(&E) -> u64,
+        heuristic: impl Fn(NodeId) -> u64,
+    ) -> Option<(u64, Vec<NodeId>)> {
+        let mut best = vec![u64::MAX; self.len()];
+        let mut previous = vec![None; self.len()];
+        let mut heap = BinaryHeap::from([Reverse((heuristic(start), 0, start))]);
+        best[start.0] = 0;
+        while let Some(Reverse((_, distance, node))) = heap.pop() {
+            if node == goal {
+                let mut path =
+                    std::iter::successors(Some(goal), |node| previous[node.0]).collect::<Vec<_>>();
+                path.reverse();
+                return Some((distance, path));
+            }
+            if distance > best[node.0] {
+                continue;
+            }
+            for (to, edge) in self.edges(node) {
+                let next = distance + cost(edge);
+                if next < best[to.0] {
+                    best[to.0] = next;
+                    previous[to.0] = Some(node);
+                    heap.push(Reverse((next + heuristic(*to), next, *to)));
+                }
+            }
+        }
+        None
+    }
+
+    pub fn shortest_path(
+        &self,
+        start: NodeId,
+        goal: NodeId,
+        cost: impl Fn(&E) -> u64,
+    ) -> Option<(u64, Vec<NodeId>)> {
+        self.astar(start, goal, cost, |_| 0)
+    }
+
+    /// Orders the nodes such that every edge points forward, or returns a cycle.
+    pub fn topological_order(&self) -> Result<Vec<NodeId>, Cycle> {
+        #[derive(Copy, Clone, PartialEq)]
+        enum State {
+            New,
+            Active,
+            Done,
+        }
+
+        let mut state = vec![State::New; self.len()];
+        let mut order = Vec::with_capacity(self.len());
+        for root in self.ids() {
+            if state[root.0] != State::New {
+                continue;
+            }
+            state[root.0] = State::Active;
+            let mut stack = vec![(root, 0)];
+            while let Some((node, edge)) = stack.last_mut() {
+                let node = *node;
+                match self.edges(node).get(*edge) {
+                    Some(&(to, _)) => {
+                        *edge += 1;
+                        match state[to.0] {
+                            State::New => {
+                                state[to.0] = State::Active;
+                                stack.push((to, 0));
+                            }
+                            State::Active => {
+                                let start = stack.iter().position(|&(x, _)| x == to).unwrap_or(0);
+                                return Err(Cycle(
+                                    stack[start..].iter().map(|&(x, _)| x).collect(),
+                                ));
+                            }
+                            State::Done => {}
+                        }
+                    }
+                    None => {
+                        state[node.0] = State::Done;
+                        order.push(node);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+        order.reverse();
+        Ok(order)
+    }
+
+    /// Computes a value for every node from the values of its successors,
+    /// visiting each node exactly once.
+    pub fn fold<T>(&self, mut f: impl FnMut(NodeId, Vec<(&E, &T)>) -> T) -> Result<Vec<T>, Cycle> {
+        let mut values: Vec<Option<T>> = std::iter::repeat_with(|| None).take(self.len()).collect();
+        for node in self.topological_order()?.into_iter().rev() {
+            let value = f(
+                node,
+                self.edges(node)
+                    .iter()
+                    .map(|(to, edge)| (edge, values[to.0].as_ref().expect("successor folded")))
+                    .collect(),
+            );
+            values[node.0] = Some(value);
+        }
+        Ok(values.into_iter().flatten().collect())
+    }
+}
+
+pub struct Bfs<'a, N, E> {
+    graph: &'a Graph<N, E>,
+    queue: VecDeque<(NodeId, usize)>,
+    seen: Vec<bool>,
+}
+
+impl<N, E> Iterator for Bfs<'_, N, E> {
+    type Item = (NodeId, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, depth) = self.queue.pop_front()?;
+        for to in self.graph.neighbours(node) {
+            if !self.seen[to.0] {
+                self.seen[to.0] = true;
+                self.queue.push_back((to, depth + 1));
+            }
+        }
+        Some((node, depth))
+    }
+}
+
+pub struct Dfs<'a, N, E> {
+    graph: &'a Graph<N, E>,
+    stack: Vec<NodeId>,
+    seen: Vec<bool>,
+}
+
+impl<N, E> Iterator for Dfs<'_, N, E> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.stack.pop() {
+            if !self.seen[node.0] {
+                self.seen[node.0] = true;
+                self.stack.extend(
+                    self.graph
+                        .neighbours(node)
+                        .filter(|to| !self.seen[to.0])
+                        .collect::<Vec<_>>()
+                        .into_iter()
+                        .rev(),
+                );
+                return Some(node);
+            }
+        }
+        None
+    }
+}
+
+/// Nodes forming a cycle, in edge order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle(pub Vec<NodeId>);
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cycle through {} nodes", self.0.len())
+    }
+}
+
+impl Error for Cycle {}
+
+/// Lowest common ancestor queries over the breadth-first trees of a graph,
+/// using binary lifting.
+pub struct Lca {
+    depth: Vec<Option<usize>>,
+    ancestors: Vec<Vec<NodeId>>,
+}
+
+impl Lca {
+    pub fn new<N, E>(graph: &Graph<N, E>, root: NodeId) -> Self {
+        Self::forest(graph, [root])
+    }
+
+    /// Builds a tree from each of `roots` in turn, leaving out nodes already in
+    /// an earlier tree. Nodes in different trees have no common ancestor.
+    pub fn forest<N, E>(graph: &Graph<N, E>, roots: impl IntoIterator<Item = NodeId>) -> Self {
+        let mut depth = vec![None; graph.len()];
+        let mut parent: Vec<NodeId> = graph.ids().collect();
+        for root in roots {
+            if depth[root.0].is_some() {
+                continue;
+            }
+            depth[root.0] = Some(0);
+            for (node, level) in graph.bfs(root) {
+                for to in graph.neighbours(node) {
+                    if depth[to.0].is_none() {
+                        depth[to.0] = Some(level + 1);
+                        parent[to.0] = node;
+                    }
+                }
+            }
+        }
+        let levels = usize::BITS - graph.len().leading_zeros();
+        let mut ancestors = vec![parent];
+        for level in 1..levels.max(1) as usize {
+            let previous = &ancestors[level - 1];
+            let next = previous.iter().map(|node| previous[node.0]).collect();
+            ancestors.push(next);
+        }
+        Self { depth, ancestors }
+    }
+
+    /// Depth of `node` below the root, if it is reachable.
+    pub fn depth(&self, node: NodeId) -> Option<usize> {
+        self.depth[node.0]
+    }
+
+    pub fn lca(&self, a: NodeId, b: NodeId) -> Option<NodeId> {
+        let (depth_a, depth_b) = (self.depth(a)?, self.depth(b)?);
+        let (mut a, mut b) = if depth_a >= depth_b { (a, b) } else { (b, a) };
+        let diff = depth_a.abs_diff(depth_b);
+        for (level, ancestors) in self.ancestors.iter().enumerate() {
+            if diff >> level & 1 == 1 {
+                a = ancestors[a.0];
+            }
+        }
+        if a == b {
+            return Some(a);
+        }
+        for ancestors in self.ancestors.iter().rev() {
+            if ancestors[a.0] != ancestors[b.0] {
+                a = ancestors[a.0];
+                b = ancestors[b.0];
+            }
+        }
+        // Roots are their own parent, so only nodes in the same tree meet.
+        let parent = &self.ancestors[0];
+        (parent[a.0] == parent[b.0]).then(|| parent[a.0])
+    }
+
+    /// Number of tree edges between `a` and `b`.
+    pub fn distance(&self, a: NodeId, b: NodeId) -> Option<usize> {
+        let lca = self.lca(a, b)?;
+        Some(self.depth(a)? + self.depth(b)? - 2 * self.depth(lca)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(&'static str, &'static str, u64)]) -> Graph<&'static str, u64> {
+        edges.iter().fold(Graph::new(), |mut graph, &(a, b, w)| {
+            graph.add_edge(a, b, w);
+            graph
+        })
+    }
+
+    #[test]
+    fn interning() {
+        let mut graph = Graph::<String>::new();
+        let a = graph.intern("a".to_string());
+        let (b, c) = graph.add_edge("b".to_string(), "a".to_string(), ());
+        assert_eq!(graph.len(), 2);
+        assert_eq!(a, c);
+        assert_eq!(graph.id("b"), Some(b));
+        assert_eq!(graph.node(b), "b");
+        assert_eq!(graph.id("z"), None);
+    }
+
+    #[test]
+    fn traversal() {
+        let graph = graph(&[("a", "b", 1), ("a", "c", 1), ("b", "d", 1), ("c", "d", 1)]);
+        let id = |x| graph.id(x).unwrap();
+        assert_eq!(
+            graph.bfs(id("a")).collect::<Vec<_>>(),
+            [(id("a"), 0), (id("b"), 1), (id("c"), 1), (id("d"), 2)]
+        );
+        assert_eq!(
+            graph.dfs(id("a")).collect::<Vec<_>>(),
+            [id("a"), id("b"), id("d"), id("c")]
+        );
+        assert_eq!(graph.reversed().bfs(id("d")).count(), 4);
+    }
+
+    #[test]
+    fn shortest_paths() {
+        let graph = graph(&[("a", "b", 7), ("a", "c", 2), ("c", "b", 3), ("b", "d", 1)]);
+        let id = |x| graph.id(x).unwrap();
+        assert_eq!(
+            graph.dijkstra(id("a"), |&w| w),
+            [Some(0), Some(5), Some(2), Some(6)]
+        );
+        assert_eq!(
+            graph.shortest_path(id("a"), id("d"), |&w| w),
+            Some((6, vec![id("a"), id("c"), id("b"), id("d")]))
+        );
+        assert_eq!(graph.astar(id("d"), id("a"), |&w| w, |_| 0), None);
+    }
+
+    #[test]
+    fn topological_order() {
+        let dag = graph(&[("a", "b", 1), ("b", "c", 1), ("a", "c", 1)]);
+        let id = |x| dag.id(x).unwrap();
+        assert_eq!(dag.topological_order(), Ok(vec![id("a"), id("b"), id("c")]));
+
+        let cyclic = graph(&[("a", "b", 1), ("b", "c", 1), ("c", "b", 1)]);
+        let id = |x| cyclic.id(x).unwrap();
+        assert_eq!(
+            cyclic.topological_order(),
+            Err(Cycle(vec![id("b"), id("c")]))
+        );
+        assert!(cyclic.fold(|_, _: Vec<(&u64, &u64)>| 0).is_err());
+    }
+
+    #[test]
+    fn fold() {
+        let dag = graph(&[("a", "b", 2), ("b", "c", 3), ("a", "c", 1)]);
+        let totals = dag
+            .fold(|_, children| children.into_iter().map(|(n, c)| n * (1 + c)).sum::<u64>())
+            .unwrap();
+        assert_eq!(totals, [2 * 4 + 1, 3, 0]);
+    }
+
+    #[test]
+    fn lowest_common_ancestor() {
+        let tree = graph(&[
+            ("r", "a", 1),
+            ("r", "b", 1),
+            ("a", "c", 1),
+            ("a", "d", 1),
+            ("d", "e", 1),
+        ]);
+        let id = |x| tree.id(x).unwrap();
+        let lca = Lca::new(&tree, id("r"));
+        assert_eq!(lca.depth(id("e")), Some(3));
+        assert_eq!(lca.lca(id("c"), id("e")), Some(id("a")));
+        assert_eq!(lca.lca(id("e"), id("b")), Some(id("r")));
+        assert_eq!(lca.lca(id("d"), id("e")), Some(id("d")));
+        assert_eq!(lca.distance(id("c"), id("e")), Some(3));
+
+        let forest = graph(&[("r", "a", 1), ("s", "b", 1), ("b", "c", 1)]);
+        let id = |x| forest.id(x).unwrap();
+        let lca = Lca::forest(&forest, [id("r"), id("s")]);
+        assert_eq!(lca.depth(id("c")), Some(2));
+        assert_eq!(lca.lca(id("c"), id("s")), Some(id("s")));
+        assert_eq!(lca.lca(id("a"), id("c")), None);
+        assert_eq!(lca.lca(id("r"), id("s")), None);
+        assert_eq!(Lca::new(&forest, id("r")).distance(id("a"), id("c")), None);
+    }
+}
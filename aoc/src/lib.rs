@@ -0,0 +1,9 @@
+mod error;
+pub mod graph;
+pub mod ocr;
+pub mod parse;
+pub mod report;
+mod solution;
+
+pub use error::{Error, Result};
+pub use solution::Solution;
@@ -0,0 +1,100 @@
+//! Recognition of the block letters puzzles draw with lit pixels.
+
+/// Letters of the 6 pixel high font, drawn with `#` for lit pixels.
+const GLYPHS: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// How many columns each letter takes up, including the gap after it. `Y`
+/// fills its whole cell, so it can touch the next letter.
+const CELL: usize = 5;
+
+/// Reads the letters in `rows` of lit pixels, one every 5 columns. Blank cells
+/// are skipped. Returns `None` if any other cell is not a known letter.
+pub fn recognise<R: AsRef<[bool]>>(rows: &[R]) -> Option<String> {
+    if rows.len() != 6 {
+        return None;
+    }
+    let width = rows.iter().map(|row| row.as_ref().len()).max()?;
+    let lit = |x: usize, y: usize| rows[y].as_ref().get(x).copied().unwrap_or(false);
+
+    let mut letters = String::new();
+    for x in (0..width).step_by(CELL) {
+        let cell = |dx: usize, y: usize| lit(x + dx, y);
+        if (0..CELL).all(|dx| (0..6).all(|y| !cell(dx, y))) {
+            continue;
+        }
+        let letter = GLYPHS.iter().find_map(|(letter, glyph)| {
+            glyph
+                .iter()
+                .enumerate()
+                .all(|(y, row)| {
+                    (0..CELL).all(|dx| (row.as_bytes().get(dx) == Some(&b'#')) == cell(dx, y))
+                })
+                .then_some(*letter)
+        })?;
+        letters.push(letter);
+    }
+    (!letters.is_empty()).then_some(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(image: &str) -> Vec<Vec<bool>> {
+        image
+            .lines()
+            .map(|line| line.chars().map(|x| x == '#').collect())
+            .collect()
+    }
+
+    #[test]
+    fn letters() {
+        let image = rows(
+            "\
+#..#.####.#....#.....##..#...#.###
+#..#.#....#....#....#..#.#...#..#.
+####.###..#....#....#..#..#.#...#.
+#..#.#....#....#....#..#...#....#.
+#..#.#....#....#....#..#...#....#.
+#..#.####.####.####..##....#...###",
+        );
+        assert_eq!(recognise(&image), Some("HELLOYI".to_string()));
+        assert_eq!(recognise(&image[..5]), None);
+        assert_eq!(recognise(&rows("#\n#\n#\n#\n#\n#")), None);
+
+        // A 25 pixel wide image where `Y` touches the letters after it.
+        let image = rows(
+            "\
+#...##..#.#...#####.#...#
+#...##..#.#...#...#.#...#
+.#.#.####..#.#...#...#.#.
+..#..#..#...#...#.....#..
+..#..#..#...#..#......#..
+..#..#..#...#..####...#..",
+        );
+        assert_eq!(recognise(&image), Some("YHYZY".to_string()));
+        assert_eq!(
+            recognise(&image[..6].iter().map(|row| &row[1..]).collect::<Vec<_>>()),
+            None
+        );
+    }
+}
@@ -0,0 +1,523 @@
+use std::{error, fmt, str::FromStr};
+
+/// A parse error located at a line and column of the original input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    line: usize,
+    column: usize,
+    snippet: String,
+    message: String,
+}
+
+impl Error {
+    pub fn new(source: &str, offset: usize, message: impl Into<String>) -> Self {
+        let offset = offset.min(source.len());
+        let start = source[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+        let end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |idx| offset + idx);
+        Self {
+            line: source[..offset].matches('\n').count() + 1,
+            column: source[start..offset].chars().count() + 1,
+            snippet: source[start..end].trim_end_matches('\r').to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Locates the error at `fragment`, which must be a subslice of `source`.
+    pub fn at(source: &str, fragment: &str, message: impl Into<String>) -> Self {
+        Self::new(source, offset(source, fragment), message)
+    }
+
+    /// Moves the error to `line`, for input parsed one line at a time.
+    pub fn with_line(self, line: usize) -> Self {
+        Self { line, ..self }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+    }
+}
+
+impl error::Error for Error {}
+
+fn offset(source: &str, fragment: &str) -> usize {
+    (fragment.as_ptr() as usize)
+        .checked_sub(source.as_ptr() as usize)
+        .filter(|&offset| offset <= source.len())
+        .unwrap_or_default()
+}
+
+/// The remaining input of a parser, remembering its position in the source.
+#[derive(Copy, Clone, Debug)]
+pub struct Input<'a> {
+    source: &'a str,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            start: 0,
+            end: source.len(),
+        }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.start..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    fn advance(self, len: usize) -> Self {
+        Self {
+            start: self.start + len,
+            ..self
+        }
+    }
+
+    /// Narrows the input to `fragment`, a subslice of the remaining input.
+    fn slice(self, fragment: &'a str) -> Self {
+        let start = offset(self.source, fragment);
+        Self {
+            start,
+            end: start + fragment.len(),
+            ..self
+        }
+    }
+
+    fn end(self) -> Self {
+        Self {
+            start: self.end,
+            ..self
+        }
+    }
+
+    pub fn fail<T>(&self, expected: impl Into<String>) -> PResult<'a, T> {
+        Err(Failure {
+            offset: self.start,
+            expected: expected.into(),
+        })
+    }
+}
+
+/// A failed parse, before it is located in the source.
+#[derive(Clone, Debug)]
+pub struct Failure {
+    offset: usize,
+    expected: String,
+}
+
+impl Failure {
+    fn locate(self, source: &str) -> Error {
+        let found = match source[self.offset..].chars().next() {
+            None => "end of input".to_string(),
+            Some('\n' | '\r') => "end of line".to_string(),
+            Some(char) => format!("`{}`", char),
+        };
+        Error::new(
+            source,
+            self.offset,
+            format!("expected {}, found {}", self.expected, found),
+        )
+    }
+}
+
+pub type PResult<'a, T> = Result<(T, Input<'a>), Failure>;
+
+pub trait Parser<'a, T> {
+    fn parse(&self, input: Input<'a>) -> PResult<'a, T>;
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(Input<'a>) -> PResult<'a, T>,
+{
+    fn parse(&self, input: Input<'a>) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+/// Runs `parser` on `source`, requiring it to consume everything but trailing whitespace.
+pub fn complete<'a, T>(parser: impl Parser<'a, T>, source: &'a str) -> Result<T, Error> {
+    all(parser)
+        .parse(Input::new(source))
+        .map(|(value, _)| value)
+        .map_err(|failure| failure.locate(source))
+}
+
+/// Requires `parser` to consume the whole input, except trailing whitespace.
+pub fn all<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input: Input<'a>| {
+        let (value, rest) = parser.parse(input)?;
+        if rest.rest().trim_end().is_empty() {
+            Ok((value, rest.end()))
+        } else {
+            rest.fail("end of line")
+        }
+    }
+}
+
+pub fn tag<'a>(tag: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| {
+        if input.rest().starts_with(tag) {
+            Ok((&input.rest()[..tag.len()], input.advance(tag.len())))
+        } else {
+            input.fail(format!("`{}`", tag))
+        }
+    }
+}
+
+/// Matches one of the given keywords, returning its associated value.
+pub fn keyword<'a, T: Clone, const N: usize>(
+    keywords: [(&'static str, T); N],
+) -> impl Parser<'a, T> {
+    move |input: Input<'a>| {
+        keywords
+            .iter()
+            .find(|(keyword, _)| input.rest().starts_with(keyword))
+            .map(|(keyword, value)| (value.clone(), input.advance(keyword.len())))
+            .map_or_else(
+                || {
+                    let keywords = keywords
+                        .iter()
+                        .map(|(keyword, _)| format!("`{}`", keyword))
+                        .collect::<Vec<_>>();
+                    input.fail(format!("one of {}", keywords.join(", ")))
+                },
+                Ok,
+            )
+    }
+}
+
+pub fn any_char<'a>() -> impl Parser<'a, char> {
+    move |input: Input<'a>| match input.rest().chars().next() {
+        Some(char) if char != '\n' => Ok((char, input.advance(char.len_utf8()))),
+        _ => input.fail("a character"),
+    }
+}
+
+pub fn take_while1<'a>(
+    expected: &'static str,
+    predicate: impl Fn(char) -> bool,
+) -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        let len = rest.find(|char| !predicate(char)).unwrap_or(rest.len());
+        if len == 0 {
+            input.fail(expected)
+        } else {
+            Ok((&rest[..len], input.advance(len)))
+        }
+    }
+}
+
+/// Everything up to, but excluding, `pattern`.
+pub fn take_until<'a>(pattern: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| match input.rest().find(pattern) {
+        Some(len) => Ok((&input.rest()[..len], input.advance(len))),
+        None => input.end().fail(format!("`{}`", pattern.escape_debug())),
+    }
+}
+
+/// A run of non-whitespace characters.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    take_while1("a word", |char| !char.is_whitespace())
+}
+
+/// One or more spaces or tabs.
+pub fn space<'a>() -> impl Parser<'a, &'a str> {
+    take_while1("whitespace", |char| char == ' ' || char == '\t')
+}
+
+pub fn integer<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        let sign = usize::from(rest.starts_with(['+', '-']));
+        let len = sign
+            + rest[sign..]
+                .find(|char: char| !char.is_ascii_digit())
+                .unwrap_or(rest.len() - sign);
+        if len == sign {
+            return input.fail("an integer");
+        }
+        match rest[..len].parse() {
+            Ok(value) => Ok((value, input.advance(len))),
+            Err(_) => input.fail("an integer in range"),
+        }
+    }
+}
+
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |input: Input<'a>| parser.parse(input).map(|(value, rest)| (f(value), rest))
+}
+
+/// Maps the output of `parser`, failing at its start position if `f` rejects it.
+pub fn map_res<'a, T, U, E: fmt::Display>(
+    parser: impl Parser<'a, T>,
+    f: impl Fn(T) -> Result<U, E>,
+) -> impl Parser<'a, U> {
+    move |input: Input<'a>| {
+        let (value, rest) = parser.parse(input)?;
+        match f(value) {
+            Ok(value) => Ok((value, rest)),
+            Err(err) => input.fail(err.to_string()),
+        }
+    }
+}
+
+pub fn opt<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    move |input: Input<'a>| match parser.parse(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(_) => Ok((None, input)),
+    }
+}
+
+pub fn preceded<'a, T, U>(
+    first: impl Parser<'a, T>,
+    second: impl Parser<'a, U>,
+) -> impl Parser<'a, U> {
+    move |input: Input<'a>| {
+        let (_, input) = first.parse(input)?;
+        second.parse(input)
+    }
+}
+
+pub fn terminated<'a, T, U>(
+    first: impl Parser<'a, T>,
+    second: impl Parser<'a, U>,
+) -> impl Parser<'a, T> {
+    move |input: Input<'a>| {
+        let (value, input) = first.parse(input)?;
+        let (_, input) = second.parse(input)?;
+        Ok((value, input))
+    }
+}
+
+pub fn pair<'a, T, U>(
+    first: impl Parser<'a, T>,
+    second: impl Parser<'a, U>,
+) -> impl Parser<'a, (T, U)> {
+    move |input: Input<'a>| {
+        let (first, input) = first.parse(input)?;
+        let (second, input) = second.parse(input)?;
+        Ok(((first, second), input))
+    }
+}
+
+/// One or more items separated by `separator`.
+pub fn separated<'a, T, U>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, U>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: Input<'a>| {
+        let (first, mut input) = item.parse(input)?;
+        let mut items = vec![first];
+        while let Ok((_, rest)) = separator.parse(input) {
+            let (next, rest) = item.parse(rest)?;
+            items.push(next);
+            input = rest;
+        }
+        Ok((items, input))
+    }
+}
+
+/// Parses every line of the input with `parser`, which must consume the whole line.
+pub fn lines<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    let parser = all(parser);
+    move |input: Input<'a>| {
+        input
+            .rest()
+            .lines()
+            .map(|line| parser.parse(input.slice(line)).map(|(value, _)| value))
+            .collect::<Result<_, _>>()
+            .map(|values| (values, input.end()))
+    }
+}
+
+/// The byte ranges of the runs of non-blank lines in `text`, without the line
+/// ending of their last line. Lines with only whitespace count as blank, so
+/// `\r\n` endings and repeated blank lines separate blocks like a single `\n\n`.
+fn block_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut current = None;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            spans.extend(current.take());
+        } else {
+            let start = current.map_or(offset, |(start, _)| start);
+            current = Some((start, offset + content.len()));
+        }
+        offset += line.len();
+    }
+    spans.extend(current);
+    spans
+}
+
+/// Parses every blank-line delimited block of the input with `parser`.
+pub fn blocks<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    let parser = all(parser);
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        block_spans(rest)
+            .into_iter()
+            .map(|(start, end)| {
+                parser
+                    .parse(input.slice(&rest[start..end]))
+                    .map(|(value, _)| value)
+            })
+            .collect::<Result<_, _>>()
+            .map(|values| (values, input.end()))
+    }
+}
+
+/// The first blank-line delimited block, consuming the blank lines after it.
+pub fn paragraph<'a>() -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        let spans = block_spans(rest);
+        match spans.first() {
+            Some(&(start, end)) => {
+                let next = spans.get(1).map_or(rest.len(), |&(next, _)| next);
+                Ok((&rest[start..end], input.advance(next)))
+            }
+            None => input.fail("a block of lines"),
+        }
+    }
+}
+
+/// Whitespace separated `key<separator>value` fields.
+pub fn record<'a, K, V>(
+    key: impl Parser<'a, K>,
+    separator: &'static str,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, Vec<(K, V)>> {
+    let field = all(pair(terminated(key, tag(separator)), value));
+    move |input: Input<'a>| {
+        input
+            .rest()
+            .split_whitespace()
+            .map(|item| field.parse(input.slice(item)).map(|(value, _)| value))
+            .collect::<Result<_, _>>()
+            .map(|fields| (fields, input.end()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        assert_eq!(complete(integer::<i32>(), "-42"), Ok(-42));
+        assert_eq!(complete(integer::<u8>(), "+7\n"), Ok(7));
+        assert_eq!(
+            complete(integer::<u8>(), "256").unwrap_err().message(),
+            "expected an integer in range, found `2`"
+        );
+        assert!(complete(integer::<u8>(), "-").is_err());
+    }
+
+    fn list<'a>() -> impl Parser<'a, Vec<u32>> {
+        separated(integer(), tag(","))
+    }
+
+    #[test]
+    fn lists() {
+        assert_eq!(complete(list(), "1,2,3"), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            complete(list(), "1,2,").unwrap_err().message(),
+            "expected an integer, found end of input"
+        );
+        assert_eq!(
+            complete(lines(list()), "1,2\n3\n"),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+        assert_eq!(
+            complete(blocks(lines(integer::<u32>())), "1\n2\n\n3\n"),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+    }
+
+    #[test]
+    fn blank_lines() {
+        let numbers = || blocks(lines(integer::<u32>()));
+        assert_eq!(
+            complete(numbers(), "\n1\n2\n\n\n \n3\n\n"),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+        assert_eq!(
+            complete(numbers(), "1\r\n2\r\n\r\n3\r\n"),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+        let err = complete(numbers(), "1\r\n\r\n\r\nx\r\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (4, 1));
+        assert_eq!(
+            complete(
+                pair(paragraph(), lines(integer::<u32>())),
+                "a\r\nb\r\n\r\n\r\n1\r\n"
+            ),
+            Ok(("a\r\nb", vec![1]))
+        );
+        assert!(complete(paragraph(), "\n \n").is_err());
+    }
+
+    fn passport<'a>() -> impl Parser<'a, Vec<(&'a str, &'a str)>> {
+        record(take_while1("a key", char::is_alphanumeric), ":", word())
+    }
+
+    #[test]
+    fn records() {
+        assert_eq!(
+            complete(passport(), "ecl:gry pid:860033327\nhcl:#fffffd"),
+            Ok(vec![
+                ("ecl", "gry"),
+                ("pid", "860033327"),
+                ("hcl", "#fffffd")
+            ])
+        );
+        let error = complete(passport(), "ecl:gry\npid 8").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 4));
+    }
+
+    #[test]
+    fn located_errors() {
+        let error = complete(lines(integer::<u32>()), "1\n2\n3x").unwrap_err();
+        assert_eq!(error.line(), 3);
+        assert_eq!(error.column(), 2);
+        assert_eq!(
+            error.to_string(),
+            "3:2: expected end of line, found `x`\n  |\n3 | 3x\n  |  ^"
+        );
+        assert_eq!(
+            complete(keyword([("up", 1), ("down", 2)]), "left")
+                .unwrap_err()
+                .message(),
+            "expected one of `up`, `down`, found `l`"
+        );
+    }
+}
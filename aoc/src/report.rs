@@ -0,0 +1,334 @@
+use crate::Error;
+use std::{fmt::Write, time::Duration};
+
+/// The outcome of running one part of a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: usize,
+    pub outcome: Outcome,
+    pub duration: Duration,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    Solved(String),
+    Failed(Error),
+    Panicked(String),
+    /// Not run, e.g. because the puzzle input is missing.
+    Skipped(String),
+}
+
+impl Outcome {
+    fn status(&self) -> &'static str {
+        match self {
+            Self::Solved(_) => "solved",
+            Self::Failed(_) => "failed",
+            Self::Panicked(_) => "panicked",
+            Self::Skipped(_) => "skipped",
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::Solved(answer) => answer.clone(),
+            Self::Failed(err) => err.to_string(),
+            Self::Panicked(message) | Self::Skipped(message) => message.clone(),
+        }
+    }
+}
+
+/// Counts of records per outcome.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub solved: usize,
+    pub failed: usize,
+    pub panicked: usize,
+    pub skipped: usize,
+}
+
+impl Summary {
+    pub fn new(records: &[Record]) -> Self {
+        records.iter().fold(Self::default(), |mut summary, record| {
+            match record.outcome {
+                Outcome::Solved(_) => summary.solved += 1,
+                Outcome::Failed(_) => summary.failed += 1,
+                Outcome::Panicked(_) => summary.panicked += 1,
+                Outcome::Skipped(_) => summary.skipped += 1,
+            }
+            summary
+        })
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.failed == 0 && self.panicked == 0
+    }
+}
+
+/// Aligned columns with one record per line, multi-line answers and
+/// diagnostics reduced to their first line.
+pub fn table(records: &[Record]) -> String {
+    let rows = records
+        .iter()
+        .map(|record| {
+            let message = record.outcome.message();
+            let mut lines = message.lines();
+            let first = lines.next().unwrap_or_default();
+            let message = match (&record.outcome, lines.next().is_some()) {
+                (Outcome::Solved(_), false) => first.to_string(),
+                (Outcome::Solved(_), true) => format!("{} …", first),
+                (outcome, _) => format!("{}: {}", outcome.status(), first),
+            };
+            [
+                record.year.to_string(),
+                record.day.to_string(),
+                record.part.to_string(),
+                format!("{:.1?}", record.duration),
+                message,
+            ]
+        })
+        .collect::<Vec<_>>();
+    let header = ["year", "day", "part", "time", "answer"];
+    let widths = rows.iter().fold(header.map(str::len), |mut widths, row| {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+        widths
+    });
+
+    let mut output = String::new();
+    for row in std::iter::once(header.map(String::from)).chain(rows) {
+        let columns = row
+            .iter()
+            .zip(widths)
+            .take(4)
+            .map(|(cell, width)| format!("{:>1$}", cell, width))
+            .collect::<Vec<_>>();
+        let _ = writeln!(output, "{}  {}", columns.join(" "), row[4]);
+    }
+    let summary = Summary::new(records);
+    let _ = write!(
+        output,
+        "{} solved, {} failed, {} panicked, {} skipped in {:.1?}",
+        summary.solved,
+        summary.failed,
+        summary.panicked,
+        summary.skipped,
+        records
+            .iter()
+            .map(|record| record.duration)
+            .sum::<Duration>()
+    );
+    output
+}
+
+/// A JSON array of `{year, day, part, status, answer, duration}` objects,
+/// with the duration in seconds.
+pub fn json(records: &[Record]) -> String {
+    let records = records
+        .iter()
+        .map(|record| {
+            let answer = match &record.outcome {
+                Outcome::Solved(answer) => format!("\"answer\":\"{}\"", escape_json(answer)),
+                outcome => format!("\"error\":\"{}\"", escape_json(&outcome.message())),
+            };
+            format!(
+                "{{\"year\":{},\"day\":{},\"part\":{},\"status\":\"{}\",{},\"duration\":{}}}",
+                record.year,
+                record.day,
+                record.part,
+                record.outcome.status(),
+                answer,
+                record.duration.as_secs_f64()
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", records.join(","))
+}
+
+/// Tab separated `year, day, part, status, answer, duration` rows after a
+/// header line, with the duration in seconds.
+pub fn tsv(records: &[Record]) -> String {
+    records.iter().fold(
+        String::from("year\tday\tpart\tstatus\tanswer\tduration\n"),
+        |mut output, record| {
+            let _ = writeln!(
+                output,
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                record.year,
+                record.day,
+                record.part,
+                record.outcome.status(),
+                escape_tsv(&record.outcome.message()),
+                record.duration.as_secs_f64()
+            );
+            output
+        },
+    )
+}
+
+/// JUnit XML with a test suite per year and a test case per part.
+pub fn junit(records: &[Record]) -> String {
+    let mut years = records.iter().map(|record| record.year).collect::<Vec<_>>();
+    years.sort_unstable();
+    years.dedup();
+
+    let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n");
+    for year in years {
+        let records = records
+            .iter()
+            .filter(|record| record.year == year)
+            .cloned()
+            .collect::<Vec<_>>();
+        let summary = Summary::new(&records);
+        let _ = writeln!(
+            output,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{}\">",
+            year,
+            records.len(),
+            summary.failed,
+            summary.panicked,
+            summary.skipped,
+            records
+                .iter()
+                .map(|record| record.duration)
+                .sum::<Duration>()
+                .as_secs_f64()
+        );
+        for record in records {
+            let _ = write!(
+                output,
+                "    <testcase classname=\"{}.day{:02}\" name=\"part {}\" time=\"{}\"",
+                record.year,
+                record.day,
+                record.part,
+                record.duration.as_secs_f64()
+            );
+            let message = escape_xml(&record.outcome.message());
+            let _ = match &record.outcome {
+                Outcome::Solved(_) => {
+                    writeln!(
+                        output,
+                        ">\n      <system-out>{}</system-out>\n    </testcase>",
+                        message
+                    )
+                }
+                Outcome::Failed(_) => writeln!(
+                    output,
+                    ">\n      <failure message=\"{0}\">{0}</failure>\n    </testcase>",
+                    message
+                ),
+                Outcome::Panicked(_) => writeln!(
+                    output,
+                    ">\n      <error message=\"{0}\">{0}</error>\n    </testcase>",
+                    message
+                ),
+                Outcome::Skipped(_) => writeln!(
+                    output,
+                    ">\n      <skipped message=\"{}\"/>\n    </testcase>",
+                    message
+                ),
+            };
+        }
+        output.push_str("  </testsuite>\n");
+    }
+    output.push_str("</testsuites>");
+    output
+}
+
+fn escape_json(input: &str) -> String {
+    input.chars().fold(String::new(), |mut output, char| {
+        match char {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\t' => output.push_str("\\t"),
+            char if char.is_control() => {
+                let _ = write!(output, "\\u{:04x}", char as u32);
+            }
+            char => output.push(char),
+        }
+        output
+    })
+}
+
+fn escape_tsv(input: &str) -> String {
+    input
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn escape_xml(input: &str) -> String {
+    input.chars().fold(String::new(), |mut output, char| {
+        match char {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\n' => output.push_str("&#10;"),
+            char => output.push(char),
+        }
+        output
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        let record = |part, outcome| Record {
+            year: 2020,
+            day: 1,
+            part,
+            outcome,
+            duration: Duration::from_millis(1),
+        };
+        vec![
+            record(1, Outcome::Solved("\"42\"\n<ok>".to_string())),
+            record(2, Outcome::Failed(Error::invalid("no answer"))),
+            record(3, Outcome::Panicked("oops".to_string())),
+        ]
+    }
+
+    #[test]
+    fn summaries() {
+        let summary = Summary::new(&records());
+        assert_eq!(
+            summary,
+            Summary {
+                solved: 1,
+                failed: 1,
+                panicked: 1,
+                skipped: 0
+            }
+        );
+        assert!(!summary.is_ok());
+        assert!(table(&records()).ends_with("1 solved, 1 failed, 1 panicked, 0 skipped in 3.0ms"));
+    }
+
+    #[test]
+    fn formats() {
+        assert!(json(&records()).starts_with(
+            r#"[{"year":2020,"day":1,"part":1,"status":"solved","answer":"\"42\"\n<ok>","duration":0.001}"#
+        ));
+        assert_eq!(
+            tsv(&records()).lines().nth(1),
+            Some("2020\t1\t1\tsolved\t\"42\"\\n<ok>\t0.001")
+        );
+        let junit = junit(&records());
+        assert!(junit.contains(
+            r#"<testsuite name="2020" tests="3" failures="1" errors="1" skipped="0" time="0.003">"#
+        ));
+        assert!(junit.contains("<system-out>&quot;42&quot;&#10;&lt;ok&gt;</system-out>"));
+        assert!(junit.contains(r#"<error message="oops">oops</error>"#));
+
+        let mut records = records();
+        records[1].year = 2019;
+        let junit = self::junit(&records);
+        assert_eq!(junit.matches("<testsuite name=\"2020\"").count(), 1);
+        assert!(junit.find("name=\"2019\"") < junit.find("name=\"2020\""));
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{
+    report::{self, Outcome, Record, Summary},
+    Error, Result,
+};
+use std::{
+    any::Any,
+    env,
+    fmt::Display,
+    fs,
+    io::{self, IsTerminal, Read},
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+type Part = Box<dyn Fn(&str) -> Result<String> + Send + Sync>;
+
+/// The parts of a puzzle solution for a given year and day.
+pub struct Solution {
+    year: u16,
+    day: u8,
+    parts: Vec<Part>,
+}
+
+impl Solution {
+    pub fn new(year: u16, day: u8) -> Self {
+        Self {
+            year,
+            day,
+            parts: Vec::new(),
+        }
+    }
+
+    pub fn part<T: Display>(
+        mut self,
+        part: impl Fn(&str) -> Result<T> + Send + Sync + 'static,
+    ) -> Self {
+        self.parts
+            .push(Box::new(move |input| part(input).map(|x| x.to_string())));
+        self
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Lazily solves the parts in order.
+    pub fn solve<'a>(&'a self, input: &'a str) -> impl Iterator<Item = Result<String>> + 'a {
+        self.parts.iter().map(move |part| part(input))
+    }
+
+    /// Solves every part, timing them and catching panics.
+    pub fn run(&self, input: &str) -> Vec<Record> {
+        self.parts
+            .iter()
+            .enumerate()
+            .map(|(idx, part)| {
+                let start = Instant::now();
+                let outcome = match panic::catch_unwind(AssertUnwindSafe(|| part(input))) {
+                    Ok(Ok(answer)) => Outcome::Solved(answer),
+                    Ok(Err(err)) => Outcome::Failed(err),
+                    Err(payload) => Outcome::Panicked(panic_message(payload)),
+                };
+                self.record(idx + 1, outcome, start.elapsed())
+            })
+            .collect()
+    }
+
+    /// Records every part as skipped for the given reason.
+    pub fn skip(&self, reason: &str) -> Vec<Record> {
+        (1..=self.parts.len())
+            .map(|part| self.record(part, Outcome::Skipped(reason.to_string()), Duration::ZERO))
+            .collect()
+    }
+
+    fn record(&self, part: usize, outcome: Outcome, duration: Duration) -> Record {
+        Record {
+            year: self.year,
+            day: self.day,
+            part,
+            outcome,
+            duration,
+        }
+    }
+
+    /// Solves the puzzle input read from the file given as argument, or from
+    /// stdin, printing a located diagnostic when it is malformed. Without an
+    /// argument on a terminal, the input is read from the `input` file instead
+    /// of waiting for stdin.
+    ///
+    /// With `--format json` or `--format tsv` every part is reported as a
+    /// `{year, day, part, answer, duration}` record instead.
+    pub fn main(&self) -> ExitCode {
+        let (format, path) = match options(env::args().skip(1)) {
+            Ok(options) => options,
+            Err(err) => {
+                eprintln!("error: {}\n{}", err, USAGE);
+                return ExitCode::FAILURE;
+            }
+        };
+        let path = match path {
+            None if io::stdin().is_terminal() => {
+                if !Path::new(DEFAULT_INPUT).is_file() {
+                    eprintln!("error: no input given\n{}", USAGE);
+                    return ExitCode::FAILURE;
+                }
+                Some(DEFAULT_INPUT.to_string())
+            }
+            path => path,
+        };
+        let name = path.as_deref().unwrap_or("<stdin>");
+        let input = match &path {
+            Some(path) => fs::read_to_string(path),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+        let input = match input {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: {}: {}", name, err);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let records = match format {
+            Format::Text => return self.print(name, &input),
+            Format::Json | Format::Tsv => self.run(&input),
+        };
+        if format == Format::Json {
+            println!("{}", report::json(&records));
+        } else {
+            print!("{}", report::tsv(&records));
+        }
+        if Summary::new(&records).is_ok() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        }
+    }
+
+    fn print(&self, name: &str, input: &str) -> ExitCode {
+        for (part, answer) in self.solve(input).enumerate() {
+            match answer {
+                Ok(answer) if answer.contains('\n') => println!("{}:\n{}", part + 1, answer),
+                Ok(answer) => println!("{}: {}", part + 1, answer),
+                Err(err @ Error::Parse(_)) => {
+                    eprintln!("error: {}:{}", name, err);
+                    return ExitCode::FAILURE;
+                }
+                Err(err) => {
+                    eprintln!("error: {}: {}", name, err);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        ExitCode::SUCCESS
+    }
+}
+
+const USAGE: &str = "usage: <solution> [--format text|json|tsv] [<input>]
+reads stdin when it is not a terminal, and the `input` file otherwise";
+
+const DEFAULT_INPUT: &str = "input";
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Format {
+    Text,
+    Json,
+    Tsv,
+}
+
+/// The output format and optional input path from the command line arguments.
+fn options(mut args: impl Iterator<Item = String>) -> Result<(Format, Option<String>), String> {
+    let mut format = Format::Text;
+    let mut path = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                format = match args.next().as_deref() {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some("tsv") => Format::Tsv,
+                    Some(format) => return Err(format!("unknown format `{}`", format)),
+                    None => return Err("missing value for `--format`".to_string()),
+                }
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag `{}`", flag)),
+            _ if path.is_none() => path = Some(arg),
+            _ => return Err(format!("unexpected argument `{}`", arg)),
+        }
+    }
+    Ok((format, path))
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run() {
+        let solution = Solution::new(2020, 1)
+            .part(|input| Ok(input.len()))
+            .part(|input| Err::<usize, _>(Error::invalid(input)))
+            .part(|input| -> Result<usize> { panic!("{} is too hard", input) });
+        let outcomes = solution
+            .run("input")
+            .into_iter()
+            .map(|record| (record.year, record.day, record.part, record.outcome))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            [
+                (2020, 1, 1, Outcome::Solved("5".to_string())),
+                (2020, 1, 2, Outcome::Failed(Error::invalid("input"))),
+                (
+                    2020,
+                    1,
+                    3,
+                    Outcome::Panicked("input is too hard".to_string())
+                )
+            ]
+        );
+        assert_eq!(solution.skip("missing").len(), 3);
+    }
+
+    #[test]
+    fn arguments() {
+        let parse = |args: &str| options(args.split_whitespace().map(String::from));
+        assert_eq!(parse(""), Ok((Format::Text, None)));
+        assert_eq!(
+            parse("--format tsv input"),
+            Ok((Format::Tsv, Some("input".to_string())))
+        );
+        assert_eq!(
+            parse("input --format json"),
+            Ok((Format::Json, Some("input".to_string())))
+        );
+        assert!(parse("--format xml").is_err());
+        assert!(parse("--format").is_err());
+        assert!(parse("--verbose").is_err());
+        assert!(parse("one two").is_err());
+    }
+}
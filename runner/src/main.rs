@@ -0,0 +1,230 @@
+use aoc::{
+    report::{self, Summary},
+    Solution,
+};
+use rayon::prelude::*;
+use std::{env, fs, panic, path::PathBuf, process::ExitCode};
+
+const USAGE: &str = "usage: aoc run (--all | <year> [<day>]) [--format table|json|junit] \
+                     [--jobs <n>] [--inputs <dir>]";
+
+fn solutions() -> Vec<Solution> {
+    vec![
+        aoc_2019_day1::solution(),
+        aoc_2019_day2::solution(),
+        aoc_2019_day3::solution(),
+        aoc_2019_day4::solution(),
+        aoc_2019_day5::solution(),
+        aoc_2019_day6::solution(),
+        aoc_2019_day7::solution(),
+        aoc_2019_day8::solution(),
+        aoc_2019_day9::solution(),
+        aoc_2020_day1::solution(),
+        aoc_2020_day2::solution(),
+        aoc_2020_day3::solution(),
+        aoc_2020_day4::solution(),
+        aoc_2020_day5::solution(),
+        aoc_2020_day6::solution(),
+        aoc_2020_day7::solution(),
+        aoc_2020_day8::solution(),
+        aoc_2021_day1::solution(),
+        aoc_2021_day2::solution(),
+        aoc_2022_01::solution(),
+        aoc_2022_02::solution(),
+        aoc_2022_03::solution(),
+        aoc_2022_04::solution(),
+        aoc_2022_05::solution(),
+        aoc_2022_06::solution(),
+        aoc_2022_07::solution(),
+        aoc_2023::day1::solution(),
+        aoc_2024::day01::solution(),
+        aoc_2024::day02::solution(),
+        aoc_2024::day03::solution(),
+    ]
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Format {
+    Table,
+    Json,
+    Junit,
+}
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    year: Option<u16>,
+    day: Option<u8>,
+    format: Format,
+    /// Worker threads, or 0 for one per core.
+    jobs: usize,
+    /// Directory with puzzle inputs at `<year>/<day>.txt`, e.g. `2019/01.txt`.
+    inputs: PathBuf,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        if args.next().as_deref() != Some("run") {
+            return Err("expected the `run` command".to_string());
+        }
+        let mut options = Options {
+            year: None,
+            day: None,
+            format: Format::Table,
+            jobs: 0,
+            inputs: PathBuf::from("inputs"),
+        };
+        let mut all = false;
+        let mut selection = Vec::new();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value for `{}`", arg));
+            match arg.as_str() {
+                "--all" => all = true,
+                "--format" => {
+                    options.format = match value()?.as_str() {
+                        "table" => Format::Table,
+                        "json" => Format::Json,
+                        "junit" => Format::Junit,
+                        format => return Err(format!("unknown format `{}`", format)),
+                    }
+                }
+                "--jobs" => {
+                    let jobs = value()?;
+                    options.jobs = jobs
+                        .parse()
+                        .map_err(|_| format!("invalid number of jobs `{}`", jobs))?;
+                }
+                "--inputs" => options.inputs = PathBuf::from(value()?),
+                flag if flag.starts_with('-') => return Err(format!("unknown flag `{}`", flag)),
+                _ => selection.push(arg),
+            }
+        }
+        match (all, selection.as_slice()) {
+            (true, []) => {}
+            (false, [year]) | (false, [year, _]) => {
+                options.year = Some(
+                    year.parse()
+                        .map_err(|_| format!("invalid year `{}`", year))?,
+                );
+                if let Some(day) = selection.get(1) {
+                    options.day = Some(day.parse().map_err(|_| format!("invalid day `{}`", day))?);
+                }
+            }
+            (true, _) => return Err("`--all` can't be combined with a year or day".to_string()),
+            (false, _) => return Err("expected `--all` or a year and optional day".to_string()),
+        }
+        Ok(options)
+    }
+
+    fn selects(&self, solution: &Solution) -> bool {
+        self.year.is_none_or(|year| year == solution.year())
+            && self.day.is_none_or(|day| day == solution.day())
+    }
+
+    fn input(&self, solution: &Solution) -> PathBuf {
+        self.inputs
+            .join(solution.year().to_string())
+            .join(format!("{:02}.txt", solution.day()))
+    }
+}
+
+fn main() -> ExitCode {
+    let options = match Options::parse(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("error: {}\n{}", err, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    let pool = match rayon::ThreadPoolBuilder::new()
+        .num_threads(options.jobs)
+        .build()
+    {
+        Ok(pool) => pool,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let solutions = solutions()
+        .into_iter()
+        .filter(|solution| options.selects(solution))
+        .collect::<Vec<_>>();
+
+    // Panics are reported with the other outcomes instead.
+    panic::set_hook(Box::new(|_| {}));
+    let records = pool.install(|| {
+        solutions
+            .par_iter()
+            .flat_map_iter(|solution| {
+                let path = options.input(solution);
+                match fs::read_to_string(&path) {
+                    Ok(input) => solution.run(&input),
+                    Err(err) => solution.skip(&format!("{}: {}", path.display(), err)),
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+    let _ = panic::take_hook();
+
+    match options.format {
+        Format::Table => println!("{}", report::table(&records)),
+        Format::Json => println!("{}", report::json(&records)),
+        Format::Junit => println!("{}", report::junit(&records)),
+    }
+
+    if Summary::new(&records).is_ok() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn parse(args: &str) -> Result<Options, String> {
+        Options::parse(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn options() {
+        assert_eq!(
+            parse("run --all --format junit --jobs 4 --inputs data"),
+            Ok(Options {
+                year: None,
+                day: None,
+                format: Format::Junit,
+                jobs: 4,
+                inputs: PathBuf::from("data"),
+            })
+        );
+        let options = parse("run 2019 8").unwrap();
+        assert_eq!((options.year, options.day), (Some(2019), Some(8)));
+        assert_eq!(
+            options.input(&aoc_2019_day8::solution()),
+            PathBuf::from("inputs/2019/08.txt")
+        );
+
+        assert!(parse("run").is_err());
+        assert!(parse("run --all 2019").is_err());
+        assert!(parse("run --all --format xml").is_err());
+        assert!(parse("run 2019 --jobs").is_err());
+        assert!(parse("solve --all").is_err());
+    }
+
+    #[test]
+    fn registry() {
+        let solutions = solutions();
+        let days = solutions
+            .iter()
+            .map(|solution| (solution.year(), solution.day()))
+            .collect::<HashSet<_>>();
+        assert_eq!(days.len(), solutions.len());
+        assert!(solutions
+            .iter()
+            .all(|solution| solution.skip("").len() == 2));
+    }
+}